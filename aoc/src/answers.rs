@@ -0,0 +1,76 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use aoc_common::Answer;
+use serde::Deserialize;
+
+use crate::{cache::Cache, days::Part, submit::AnswerLog, submit::Verdict};
+
+/// Answer of a part registered in `answers.toml`
+#[derive(Debug, Deserialize)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub expected: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Answers {
+    #[serde(default)]
+    answer: Vec<Expected>,
+}
+
+/// Load the answers registered in an `answers.toml` file
+pub fn load(path: &Path) -> Result<Vec<Expected>> {
+    let content = fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let answers: Answers =
+        toml::from_str(&content).with_context(|| format!("parse {}", path.display()))?;
+    Ok(answers.answer)
+}
+
+/// Gather the known correct answers from `answers.toml` and the answer log
+pub fn known(registry: &Path, cache: &Cache) -> Result<HashMap<(u8, Part), Answer>> {
+    let mut known = HashMap::new();
+    if registry.exists() {
+        for answer in load(registry)? {
+            let expected = answer.expected.parse()?;
+            known.insert((answer.day, answer.part), expected);
+        }
+    }
+    for attempt in AnswerLog::open(cache)?.attempts() {
+        if attempt.verdict == Verdict::Correct {
+            known.insert((attempt.day, attempt.part), attempt.answer.clone());
+        }
+    }
+    Ok(known)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_known() -> Result<()> {
+        let dir = env::temp_dir().join("aoc-test-known-answers");
+        fs::create_dir_all(&dir)?;
+        let registry = dir.join("answers.toml");
+        fs::write(
+            &registry,
+            "[[answer]]\nday = 1\npart = 1\nexpected = \"142\"\n",
+        )?;
+        fs::write(
+            dir.join("answers.json"),
+            r#"[{"day":1,"part":2,"answer":"281","verdict":"correct","submitted_at":0,"retry_at":null},
+                {"day":2,"part":1,"answer":"7","verdict":"too_low","submitted_at":0,"retry_at":null}]"#,
+        )?;
+
+        let known = known(&registry, &Cache::new(&dir))?;
+        fs::remove_dir_all(&dir)?;
+        assert_eq!(known.len(), 2);
+        assert_eq!(known[&(1, Part::One)], 142.into());
+        assert_eq!(known[&(1, Part::Two)], 281.into());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// Environment variable overriding the cache directory
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Cache directory used when `AOC_CACHE_DIR` is not set
+pub const DEFAULT_CACHE_DIR: &str = ".aoc";
+
+/// On-disk cache of the downloaded puzzle data, keyed by year and day
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// Open the cache from `AOC_CACHE_DIR` or the default directory
+    pub fn from_env() -> Self {
+        Cache::new(env::var_os(CACHE_DIR_VAR).unwrap_or_else(|| DEFAULT_CACHE_DIR.into()))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Return the cached input file of a day
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// Return the cached input of a day, if it was already downloaded
+    pub fn input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        let path = self.input_path(year, day);
+        if !path.exists() {
+            return Ok(None);
+        }
+        fs::read_to_string(&path)
+            .map(Some)
+            .with_context(|| format!("read cached input {}", path.display()))
+    }
+
+    /// Store the input of a day and return where it was written
+    pub fn store_input(&self, year: u16, day: u8, input: &str) -> Result<PathBuf> {
+        let path = self.input_path(year, day);
+        write(&path, input)?;
+        Ok(path)
+    }
+}
+
+/// Write a file, creating its parent directories
+pub(crate) fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let cache = Cache::new("cache");
+        assert_eq!(
+            cache.input_path(2023, 5),
+            PathBuf::from("cache/2023/day-05.txt")
+        );
+    }
+
+    #[test]
+    fn test_store_input() -> Result<()> {
+        let cache = Cache::new(env::temp_dir().join("aoc-test-store-input"));
+        assert_eq!(cache.input(2023, 1)?, None);
+        cache.store_input(2023, 1, "1abc2")?;
+        assert_eq!(cache.input(2023, 1)?.as_deref(), Some("1abc2"));
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+}
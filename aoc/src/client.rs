@@ -0,0 +1,244 @@
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{
+    cache::{self, Cache},
+    days::Part,
+};
+
+/// Year of the calendar solved by this workspace
+pub const YEAR: u16 = 2023;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the base URL
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Minimum delay between two requests to the server
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(
+    "github.com/ledo01/aoc2023 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Client of the puzzle server, caching everything it downloads
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache: Cache,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<&str>, cache: Cache) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::to_string),
+            cache,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Set the minimum delay between two requests
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// Return the input of a day, only downloading it when it is not cached
+    pub fn input(&self, day: u8) -> Result<String> {
+        if let Some(input) = self.cache.input(YEAR, day)? {
+            return Ok(input);
+        }
+        let input = self
+            .get(&format!("/{YEAR}/day/{day}/input"))
+            .with_context(|| format!("fetch input of day {day}"))?;
+        self.cache.store_input(YEAR, day, &input)?;
+        Ok(input)
+    }
+
+    /// Post an answer and return the raw response page
+    pub fn answer(&self, day: u8, part: Part, answer: &str) -> Result<String> {
+        let session = self.session()?;
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(&format!("{}/{YEAR}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={session}"))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        self.send_done()?;
+        read_response(response).with_context(|| format!("submit answer of day {day} part {part}"))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let session = self.session()?;
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        self.send_done()?;
+        read_response(response)
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .ok_or(anyhow!("missing session token, set {SESSION_VAR}"))
+    }
+
+    /// Wait until the minimum delay since the last request is elapsed
+    pub(crate) fn throttle(&self) -> Result<()> {
+        let path = self.cache.dir().join("last-request");
+        let Ok(last) = fs::read_to_string(&path) else {
+            return Ok(());
+        };
+        let last = Duration::from_millis(last.trim().parse().unwrap_or(0));
+        let elapsed = now()?.saturating_sub(last);
+        if elapsed < self.min_interval {
+            thread::sleep(self.min_interval - elapsed);
+        }
+        Ok(())
+    }
+
+    /// Remember when the last request was sent
+    pub(crate) fn send_done(&self) -> Result<()> {
+        let path = self.cache.dir().join("last-request");
+        cache::write(&path, &now()?.as_millis().to_string())
+    }
+}
+
+/// Return the time elapsed since the unix epoch
+pub(crate) fn now() -> Result<Duration> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?)
+}
+
+/// Turn the response into its body, with a meaningful error on failures
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(404, _)) => bail!("not found, the puzzle may not be unlocked yet"),
+        Err(ureq::Error::Status(400 | 500, _)) => {
+            bail!("request rejected, check the session token in {SESSION_VAR}")
+        }
+        Err(ureq::Error::Status(code, response)) => Err(anyhow!(
+            "unexpected status {code}: {}",
+            response.into_string().unwrap_or_default()
+        )),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Instant};
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn client(name: &str, server: &MockServer) -> Client {
+        let cache = Cache::new(env::temp_dir().join(name));
+        let _ = fs::remove_dir_all(cache.dir());
+        Client::new(&server.url(), Some("secret"), cache).min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_input() -> Result<()> {
+        let server = MockServer::start(vec![(200, "1abc2\n".to_string())]);
+        let client = client("aoc-test-fetch-input", &server);
+        assert_eq!(client.input(1)?, "1abc2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2023/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert!(requests[0]
+            .header("user-agent")
+            .is_some_and(|agent| agent.contains("ledo01/aoc2023")));
+
+        fs::remove_dir_all(client.cache().dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_cached() -> Result<()> {
+        let server = MockServer::start(vec![(200, "input".to_string())]);
+        let client = client("aoc-test-fetch-input-cached", &server);
+        assert_eq!(client.input(2)?, "input");
+        assert_eq!(client.input(2)?, "input");
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_dir_all(client.cache().dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_not_found() -> Result<()> {
+        let server = MockServer::start(vec![(404, "Not Found".to_string())]);
+        let client = client("aoc-test-fetch-input-not-found", &server);
+        assert!(client.input(25).is_err());
+        assert_eq!(client.cache().input(YEAR, 25)?, None);
+
+        fs::remove_dir_all(client.cache().dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_post_answer() -> Result<()> {
+        let server = MockServer::start(vec![(200, "That's the right answer!".to_string())]);
+        let client = client("aoc-test-post-answer", &server);
+        assert_eq!(
+            client.answer(3, Part::Two, "467835")?,
+            "That's the right answer!"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/3/answer");
+        assert_eq!(requests[0].body, "level=2&answer=467835");
+
+        fs::remove_dir_all(client.cache().dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_without_session() {
+        let client = Client::new(DEFAULT_BASE_URL, None, Cache::new("does/not/exist"));
+        let err = client.input(1).unwrap_err();
+        assert!(format!("{err:#}").contains(SESSION_VAR));
+    }
+
+    #[test]
+    fn test_throttle() -> Result<()> {
+        let server = MockServer::start(vec![]);
+        let client = client("aoc-test-throttle", &server).min_interval(Duration::from_millis(200));
+        client.send_done()?;
+        let start = Instant::now();
+        client.throttle()?;
+        assert!(start.elapsed() >= Duration::from_millis(150));
+
+        fs::remove_dir_all(client.cache().dir())?;
+        Ok(())
+    }
+}
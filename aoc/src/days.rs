@@ -0,0 +1,377 @@
+use std::{
+    any::Any,
+    error, fmt,
+    panic::{self, UnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{Answer, Solver};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use tracing::info_span;
+
+use crate::memory::{self, Allocations};
+
+/// Entry point of a solver, from the raw input to the answer
+pub type Process = fn(&str) -> Result<Answer>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part: {s}, expected 1 or 2")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        value.to_string().parse()
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Timed run of a solver, parsing the input once for every part
+pub type Timed = fn(&str, &[Part]) -> Result<Timings>;
+
+/// Generator of random inputs of a given size
+pub type Generate = fn(&mut ChaCha8Rng, usize) -> String;
+
+/// Slow but obviously correct solutions of both parts, to check the solvers against
+pub type Oracle = [Process; 2];
+
+/// Wall time spent parsing the input and solving each part, with the allocations
+/// made when they are counted
+#[derive(Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parse_allocations: Option<Allocations>,
+    pub parts: Vec<PartTiming>,
+}
+
+#[derive(Debug)]
+pub struct PartTiming {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub solve: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+fn timed<S: Solver>(input: &str, parts: &[Part]) -> Result<Timings> {
+    let _day = info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let (parsed, parse_allocations) = memory::measure(|| S::parse(input));
+    let parse = start.elapsed();
+    let parsed = parsed?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let _part = info_span!("part", %part).entered();
+            let start = Instant::now();
+            let (answer, allocations) = memory::measure(|| match part {
+                Part::One => S::part1(&parsed),
+                Part::Two => S::part2(&parsed),
+            });
+            PartTiming {
+                part,
+                answer,
+                solve: start.elapsed(),
+                allocations,
+            }
+        })
+        .collect();
+    Ok(Timings {
+        parse,
+        parse_allocations,
+        parts,
+    })
+}
+
+#[derive(Debug)]
+pub struct Day {
+    pub day: u8,
+    pub part1: Process,
+    pub part2: Process,
+    pub timed: Timed,
+    pub generate: Option<Generate>,
+    pub oracle: Option<Oracle>,
+}
+
+impl Day {
+    /// Run the given part against the raw input
+    pub fn process(&self, part: Part, input: &str) -> Result<Answer> {
+        let _span = info_span!("day", day = self.day).entered();
+        let _part = info_span!("part", %part).entered();
+        match part {
+            Part::One => (self.part1)(input),
+            Part::Two => (self.part2)(input),
+        }
+    }
+
+    /// Run the given parts against the raw input, timing each phase
+    pub fn timed(&self, input: &str, parts: &[Part]) -> Result<Timings> {
+        (self.timed)(input, parts)
+    }
+
+    /// Generate a random input of the given size, always the same for a seed
+    pub fn generate(&self, size: usize, seed: u64) -> Result<String> {
+        let generate = self
+            .generate
+            .ok_or(anyhow!("day {} has no input generator", self.day))?;
+        Ok(generate(&mut ChaCha8Rng::seed_from_u64(seed), size))
+    }
+
+    /// Run the oracle of the given part against the raw input
+    pub fn oracle(&self, part: Part, input: &str) -> Result<Answer> {
+        let [part1, part2] = self
+            .oracle
+            .ok_or(anyhow!("day {} has no oracle", self.day))?;
+        match part {
+            Part::One => part1(input),
+            Part::Two => part2(input),
+        }
+    }
+}
+
+/// Every solved day of the calendar, in order
+pub const DAYS: [Day; 11] = [
+    Day {
+        day: 1,
+        part1: day_1::part1::process,
+        part2: day_1::part2::process,
+        timed: timed::<day_1::Day01>,
+        generate: None,
+        oracle: None,
+    },
+    Day {
+        day: 2,
+        part1: day_2::part1::process,
+        part2: day_2::part2::process,
+        timed: timed::<day_2::Day02>,
+        generate: Some(day_2::generate::generate),
+        oracle: Some([day_2::oracle::part1, day_2::oracle::part2]),
+    },
+    Day {
+        day: 3,
+        part1: day_03::part1::process,
+        part2: day_03::part2::process,
+        timed: timed::<day_03::Day03>,
+        generate: Some(day_03::generate::generate),
+        oracle: Some([day_03::oracle::part1, day_03::oracle::part2]),
+    },
+    Day {
+        day: 4,
+        part1: day_04::part1::process,
+        part2: day_04::part2::process,
+        timed: timed::<day_04::Day04>,
+        generate: Some(day_04::generate::generate),
+        oracle: Some([day_04::oracle::part1, day_04::oracle::part2]),
+    },
+    Day {
+        day: 5,
+        part1: day_05::part1::process,
+        part2: day_05::part2::process,
+        timed: timed::<day_05::Day05>,
+        generate: Some(day_05::generate::generate),
+        oracle: Some([day_05::oracle::part1, day_05::oracle::part2]),
+    },
+    Day {
+        day: 6,
+        part1: day_06::part1::process,
+        part2: day_06::part2::process,
+        timed: timed::<day_06::Day06>,
+        generate: Some(day_06::generate::generate),
+        oracle: Some([day_06::oracle::part1, day_06::oracle::part2]),
+    },
+    Day {
+        day: 7,
+        part1: day_07::part1::process,
+        part2: day_07::part2::process,
+        timed: timed::<day_07::Day07>,
+        generate: Some(day_07::generate::generate),
+        oracle: Some([day_07::oracle::part1, day_07::oracle::part2]),
+    },
+    Day {
+        day: 8,
+        part1: day_08::part1::process,
+        part2: day_08::part2::process,
+        timed: timed::<day_08::Day08>,
+        generate: Some(day_08::generate::generate),
+        oracle: Some([day_08::oracle::part1, day_08::oracle::part2]),
+    },
+    Day {
+        day: 9,
+        part1: day_09::part1::process,
+        part2: day_09::part2::process,
+        timed: timed::<day_09::Day09>,
+        generate: Some(day_09::generate::generate),
+        oracle: Some([day_09::oracle::part1, day_09::oracle::part2]),
+    },
+    Day {
+        day: 10,
+        part1: day_10::part1::process,
+        part2: day_10::part2::process,
+        timed: timed::<day_10::Day10>,
+        generate: Some(day_10::generate::generate),
+        oracle: Some([day_10::oracle::part1, day_10::oracle::part2]),
+    },
+    Day {
+        day: 11,
+        part1: day_11::part1::process,
+        part2: day_11::part2::process,
+        timed: timed::<day_11::Day11>,
+        generate: Some(day_11::generate::generate),
+        oracle: Some([day_11::oracle::part1, day_11::oracle::part2]),
+    },
+];
+
+/// Return the registered solver of a day
+pub fn get(day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.day == day)
+        .ok_or(anyhow!("day {day} is not solved yet"))
+}
+
+/// Panic of a solver, caught by [`catch_panic`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    /// Message the solver panicked with
+    pub message: String,
+}
+
+impl Panic {
+    /// Recover the message of a caught panic payload
+    pub fn new(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown panic".to_string(),
+            },
+        };
+        Panic { message }
+    }
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "solver panicked: {}", self.message)
+    }
+}
+
+impl error::Error for Panic {}
+
+/// Run a solver, turning a panic into a [`Panic`] error carrying its message
+pub fn catch_panic<T>(run: impl FnOnce() -> Result<T> + UnwindSafe) -> Result<T> {
+    panic::catch_unwind(run).unwrap_or_else(|payload| Err(Panic::new(payload).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        assert!(DAYS.windows(2).all(|w| w[0].day + 1 == w[1].day));
+    }
+
+    #[test]
+    fn test_get() -> Result<()> {
+        assert_eq!(get(5)?.day, 5);
+        assert!(get(25).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| Ok(42)).unwrap(), 42);
+        let error = catch_panic(|| -> Result<()> { panic!("no way {}", 42) }).unwrap_err();
+        assert_eq!(error.to_string(), "solver panicked: no way 42");
+        assert_eq!(error.downcast_ref::<Panic>().unwrap().message, "no way 42");
+    }
+
+    #[test]
+    fn test_process() -> Result<()> {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        assert_eq!(get(9)?.process(Part::One, input)?, 114.into());
+        assert_eq!(get(9)?.process(Part::Two, input)?, 2.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timed() -> Result<()> {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let timings = get(9)?.timed(input, &Part::ALL)?;
+        assert_eq!(timings.parts.len(), 2);
+        assert_eq!(timings.parts[0].part, Part::One);
+        assert_eq!(timings.parts[0].answer.as_ref().unwrap(), &114.into());
+        assert_eq!(timings.parts[1].answer.as_ref().unwrap(), &2.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = get(9)?.generate(10, 42)?;
+        assert_eq!(input, get(9)?.generate(10, 42)?);
+        assert_ne!(input, get(9)?.generate(10, 43)?);
+        assert_eq!(input.lines().count(), 10);
+        assert!(get(1)?.generate(10, 42).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        assert_eq!(get(9)?.oracle(Part::One, input)?, 114.into());
+        assert_eq!(get(9)?.oracle(Part::Two, input)?, 2.into());
+        assert!(get(1)?.oracle(Part::One, "1abc2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! Differential testing of the solvers against their oracles on generated inputs
+
+use std::{fmt, ops::Range, panic::UnwindSafe};
+
+use anyhow::Result;
+use aoc_common::Answer;
+
+use crate::days::{self, Day, Panic, Part};
+
+/// Part answering differently from its oracle on a generated input
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub size: usize,
+    pub seed: u64,
+    /// Answer of the oracle
+    pub expected: String,
+    /// Answer of the solver
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {} answered {} instead of {} on the input of `aoc gen --day {} --size {} --seed {}`",
+            self.day, self.part, self.actual, self.expected, self.day, self.size, self.seed
+        )
+    }
+}
+
+/// Answer or error of a run, a panic being reported rather than unwinding
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Answer(String),
+    Error(String),
+    Panic(String),
+}
+
+impl Outcome {
+    fn new(run: impl FnOnce() -> Result<Answer> + UnwindSafe) -> Self {
+        match days::catch_panic(run) {
+            Ok(answer) => Outcome::Answer(answer.to_string()),
+            Err(e) => match e.downcast::<Panic>() {
+                Ok(panic) => Outcome::Panic(panic.message),
+                Err(e) => Outcome::Error(e.to_string()),
+            },
+        }
+    }
+
+    /// Whether both runs agree, a panic never agreeing with anything
+    fn agrees(&self, other: &Outcome) -> bool {
+        !matches!(self, Outcome::Panic(_)) && self == other
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Error(e) => write!(f, "error `{e}`"),
+            Outcome::Panic(message) => write!(f, "a panic `{message}`"),
+        }
+    }
+}
+
+/// Run both parts of the day and of its oracle on the input generated from each seed,
+/// stopping at the first mismatch
+pub fn check(day: &Day, size: usize, seeds: Range<u64>) -> Result<Option<Mismatch>> {
+    for seed in seeds {
+        let input = day.generate(size, seed)?;
+        for part in Part::ALL {
+            let expected = Outcome::new(|| day.oracle(part, &input));
+            let actual = Outcome::new(|| day.process(part, &input));
+            if !actual.agrees(&expected) {
+                return Ok(Some(Mismatch {
+                    day: day.day,
+                    part,
+                    size,
+                    seed,
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, DAYS};
+
+    #[test]
+    fn test_check() -> Result<()> {
+        for day in DAYS.iter().filter(|day| day.oracle.is_some()) {
+            assert_eq!(check(day, 20, 0..20)?, None, "day {}", day.day);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_mismatch() -> Result<()> {
+        let day = days::get(9)?;
+        // Swapping the oracles of the parts makes them disagree on any input
+        let swapped = Day {
+            oracle: Some([day_09::oracle::part2, day_09::oracle::part1]),
+            ..*day
+        };
+        let mismatch = check(&swapped, 5, 3..10)?.expect("a mismatch");
+        assert_eq!((mismatch.part, mismatch.seed), (Part::One, 3));
+        assert_eq!(
+            mismatch.actual,
+            day.process(Part::One, &day.generate(5, 3)?)?.to_string()
+        );
+        assert!(mismatch
+            .to_string()
+            .contains("aoc gen --day 9 --size 5 --seed 3"));
+
+        assert!(check(days::get(1)?, 5, 0..1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_panic() -> Result<()> {
+        fn panics(_: &str) -> Result<Answer> {
+            panic!("no way")
+        }
+        // Panicking on both sides still counts as a mismatch
+        let day = Day {
+            part1: panics,
+            oracle: Some([panics, day_09::oracle::part2]),
+            ..*days::get(9)?
+        };
+        let mismatch = check(&day, 5, 0..10)?.expect("a mismatch");
+        assert_eq!((mismatch.part, mismatch.seed), (Part::One, 0));
+        assert_eq!(mismatch.actual, "a panic `no way`");
+        assert_eq!(mismatch.expected, "a panic `no way`");
+        Ok(())
+    }
+}
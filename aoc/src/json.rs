@@ -0,0 +1,205 @@
+use anyhow::{Error, Result};
+use aoc_common::diagnostic::Diagnostic;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    days::{Day, Part},
+    memory::Allocations,
+};
+
+/// Outcome of running a part, as emitted by `--format json`
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// Allocations of the parse and of the solve, only present when built with
+    /// `alloc-count`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_allocations: Option<Allocations>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solve_allocations: Option<Allocations>,
+    /// SHA-256 of the raw input
+    pub input_hash: Option<String>,
+    pub error: Option<RunError>,
+}
+
+/// Why a part failed, with its location in the input when known
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl From<&Error> for RunError {
+    fn from(error: &Error) -> Self {
+        match error.chain().find_map(|e| e.downcast_ref::<Diagnostic>()) {
+            Some(diagnostic) => {
+                let (line, column) = diagnostic.location();
+                RunError {
+                    message: diagnostic.message.clone(),
+                    line: Some(line),
+                    column: Some(column),
+                }
+            }
+            None => RunError {
+                message: format!("{error:#}"),
+                line: None,
+                column: None,
+            },
+        }
+    }
+}
+
+/// Return the hex encoded SHA-256 of an input
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Run the given parts of a day, recording failures instead of stopping
+pub fn runs(day: &Day, input: Result<String>, parts: &[Part]) -> Vec<Run> {
+    let failed = |part, hash: Option<&String>, error: &Error| Run {
+        day: day.day,
+        part,
+        answer: None,
+        parse_ns: None,
+        solve_ns: None,
+        parse_allocations: None,
+        solve_allocations: None,
+        input_hash: hash.cloned(),
+        error: Some(error.into()),
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return parts.iter().map(|&part| failed(part, None, &e)).collect(),
+    };
+    let hash = input_hash(&input);
+    let timings = match day.timed(&input, parts) {
+        Ok(timings) => timings,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| failed(part, Some(&hash), &e))
+                .collect()
+        }
+    };
+    timings
+        .parts
+        .into_iter()
+        .map(|part| match part.answer {
+            Ok(answer) => Run {
+                day: day.day,
+                part: part.part,
+                answer: Some(answer.to_string()),
+                parse_ns: Some(timings.parse.as_nanos() as u64),
+                solve_ns: Some(part.solve.as_nanos() as u64),
+                parse_allocations: timings.parse_allocations,
+                solve_allocations: part.allocations,
+                input_hash: Some(hash.clone()),
+                error: None,
+            },
+            Err(e) => Run {
+                parse_ns: Some(timings.parse.as_nanos() as u64),
+                solve_ns: Some(part.solve.as_nanos() as u64),
+                parse_allocations: timings.parse_allocations,
+                solve_allocations: part.allocations,
+                ..failed(part.part, Some(&hash), &e)
+            },
+        })
+        .collect()
+}
+
+/// Render the runs as a pretty printed JSON array, one object per part
+pub fn render(runs: &[Run]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(runs)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_runs() -> Result<()> {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        let runs = runs(days::get(9)?, Ok(input.to_string()), &Part::ALL);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].answer.as_deref(), Some("114"));
+        assert_eq!(runs[1].answer.as_deref(), Some("2"));
+        assert_eq!(runs[1].input_hash, Some(input_hash(input)));
+        assert!(runs.iter().all(|run| run.error.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_runs_parse_error() -> Result<()> {
+        let runs = runs(days::get(2)?, Ok("Game 1; 3 blue".into()), &[Part::Two]);
+        assert_eq!(
+            runs[0].error,
+            Some(RunError {
+                message: "invalid game: expected `: `".into(),
+                line: Some(1),
+                column: Some(7),
+            })
+        );
+        assert_eq!(runs[0].answer, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_runs_missing_input() -> Result<()> {
+        let runs = runs(days::get(2)?, Err(anyhow!("missing input")), &Part::ALL);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].input_hash, None);
+        assert_eq!(runs[1].error.as_ref().unwrap().message, "missing input");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let run = Run {
+            day: 9,
+            part: Part::One,
+            answer: Some("114".into()),
+            parse_ns: Some(1500),
+            solve_ns: Some(200),
+            parse_allocations: None,
+            solve_allocations: None,
+            input_hash: Some("abc".into()),
+            error: None,
+        };
+        assert_eq!(
+            render(&[run])?,
+            r#"[
+  {
+    "day": 9,
+    "part": 1,
+    "answer": "114",
+    "parse_ns": 1500,
+    "solve_ns": 200,
+    "input_hash": "abc",
+    "error": null
+  }
+]"#
+        );
+        Ok(())
+    }
+}
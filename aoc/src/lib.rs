@@ -0,0 +1,24 @@
+#[cfg(feature = "cli")]
+pub mod answers;
+pub mod cache;
+#[cfg(feature = "cli")]
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod json;
+pub mod memory;
+#[cfg(all(test, feature = "cli"))]
+mod mock;
+pub mod report;
+pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "cli")]
+pub mod submit;
+pub mod summary;
+#[cfg(feature = "cli")]
+pub mod trace;
+#[cfg(feature = "cli")]
+pub mod tui;
+#[cfg(feature = "cli")]
+pub mod watch;
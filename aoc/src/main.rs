@@ -0,0 +1,422 @@
+#[cfg(feature = "serve")]
+use std::time::Duration;
+use std::{env, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::input::{self, Source};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[cfg(feature = "serve")]
+use aoc::serve::{self, Limits};
+use aoc::{
+    answers,
+    cache::Cache,
+    client::{Client, BASE_URL_VAR, DEFAULT_BASE_URL, SESSION_VAR},
+    days::{self, Day, Part},
+    differential, json, report, scaffold,
+    submit::{self, AnswerLog},
+    summary, trace,
+    tui::{self, App},
+    watch,
+};
+
+#[cfg(feature = "alloc-count")]
+#[global_allocator]
+static ALLOCATOR: aoc::memory::Counting = aoc::memory::Counting;
+
+#[derive(Parser)]
+#[command(version, about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver of a day against its input
+    Run(RunArgs),
+    /// Download the input of a day into the cache and the inputs directory
+    Fetch(FetchArgs),
+    /// Solve a part and submit its answer
+    Submit(SubmitArgs),
+    /// Run every day and print a table of the answers and timings
+    Summary(SummaryArgs),
+    /// Create the crate of a new day from the template and register it in the runner
+    New(NewArgs),
+    /// Browse the calendar and run days interactively
+    Tui,
+    /// Test and run a day again every time its sources or input change
+    Watch(WatchArgs),
+    /// Generate a random input for a day, printed to stdout
+    Gen(GenArgs),
+    /// Check the solvers against their slow oracles on generated inputs
+    Diff(DiffArgs),
+    /// Serve the solvers over HTTP at `POST /day/<n>/part/<p>`
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run when omitted
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Puzzle input file, `-` for stdin, defaults to `$AOC_INPUT_DIR/day-NN.txt`
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Run every solved day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Record the parse and solve spans of the run
+    #[arg(long, value_enum)]
+    trace: Option<Trace>,
+
+    /// File the Chrome trace is written to
+    #[arg(long, default_value = "trace.json")]
+    trace_file: PathBuf,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One line per part
+    Text,
+    /// Array of runs with timings, input hash and error location
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Trace {
+    /// Tree of the spans and events with their timings, printed to stderr
+    Tree,
+    /// Chrome trace JSON, written to `--trace-file`
+    Chrome,
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Session cookie of the puzzle server
+    #[arg(long, env = SESSION_VAR, hide_env_values = true)]
+    session: Option<String>,
+
+    /// Base URL of the puzzle server
+    #[arg(long, env = BASE_URL_VAR, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl ServerArgs {
+    fn client(&self) -> Client {
+        Client::new(&self.base_url, self.session.as_deref(), Cache::from_env())
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download
+    #[arg(short, long)]
+    day: u8,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part to submit
+    #[arg(short, long)]
+    part: Part,
+
+    /// Puzzle input file, `-` for stdin, defaults to `$AOC_INPUT_DIR/day-NN.txt`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args)]
+struct SummaryArgs {
+    /// Print a markdown table, ready to paste into the README
+    #[arg(long)]
+    markdown: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(short, long)]
+    day: u8,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(short, long)]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+
+    /// Size of the input, such as the number of lines
+    #[arg(short, long)]
+    size: usize,
+
+    /// Seed of the generator, the same seed giving the same input
+    #[arg(long, default_value_t = 2023)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Day to check, every day with an oracle if missing
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Size of the generated inputs, kept small for the oracles to be quick
+    #[arg(short, long, default_value_t = 20)]
+    size: usize,
+
+    /// Number of inputs to generate, from the seeds 0 and up
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+}
+
+#[cfg(feature = "serve")]
+#[derive(Args)]
+struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+
+    /// Largest accepted input, in bytes
+    #[arg(long, default_value_t = serve::DEFAULT_MAX_BODY)]
+    max_body: usize,
+
+    /// Seconds a part may run before its request fails
+    #[arg(long, default_value_t = 10)]
+    timeout: u64,
+
+    /// Number of requests handled at once
+    #[arg(long, default_value_t = 4)]
+    threads: usize,
+
+    /// Number of solvers running at once, including the ones still running after
+    /// their request timed out, further requests failing with a 503
+    #[arg(long, default_value_t = serve::DEFAULT_MAX_SOLVERS)]
+    max_solvers: usize,
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    match args.trace {
+        None => run_days(&args),
+        Some(Trace::Tree) => {
+            let (result, tree) = trace::tree(|| run_days(&args));
+            eprint!("{tree}");
+            result
+        }
+        Some(Trace::Chrome) => {
+            let result = trace::chrome(&args.trace_file, || run_days(&args))?;
+            eprintln!("trace written to {}", args.trace_file.display());
+            result
+        }
+    }
+}
+
+fn run_days(args: &RunArgs) -> Result<()> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![days::get(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if args.format == Format::Json {
+        let runs: Vec<_> = days
+            .into_iter()
+            .flat_map(|day| {
+                let input = Source::resolve(day.day, args.input.as_deref()).read();
+                json::runs(day, input, &parts)
+            })
+            .collect();
+        println!("{}", json::render(&runs)?);
+        let failures = runs.iter().filter(|run| run.error.is_some()).count();
+        if failures > 0 {
+            bail!("{failures} part(s) failed");
+        }
+        return Ok(());
+    }
+
+    let mut failures = 0;
+    for day in days {
+        let source = Source::resolve(day.day, args.input.as_deref());
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {:02}: {e:#}", day.day);
+                failures += parts.len();
+                continue;
+            }
+        };
+        for &part in &parts {
+            match day.process(part, &input) {
+                Ok(answer) => println!("Day {:02} part {part}: {answer}", day.day),
+                Err(e) => {
+                    eprintln!(
+                        "Day {:02} part {part}: {}",
+                        day.day,
+                        report::render(&e, &source.to_string())
+                    );
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let input = args.server.client().input(args.day)?;
+    let dir = input::input_dir();
+    let path = input::day_path(&dir, args.day);
+    if !path.exists() {
+        fs::create_dir_all(&dir).with_context(|| format!("create {}", dir.display()))?;
+        fs::write(&path, input).with_context(|| format!("write {}", path.display()))?;
+    }
+    println!("{}", path.display());
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let day = days::get(args.day)?;
+    let source = Source::resolve(day.day, args.input.as_deref());
+    let input = source.read()?;
+    let answer = match day.process(args.part, &input) {
+        Ok(answer) => answer,
+        Err(e) => bail!("{}", report::render(&e, &source.to_string())),
+    };
+
+    let client = args.server.client();
+    let mut log = AnswerLog::open(client.cache())?;
+    let attempt = submit::submit(&client, &mut log, day.day, args.part, &answer)?;
+    println!(
+        "Day {:02} part {}: {answer} is {}",
+        day.day, args.part, attempt.verdict
+    );
+    Ok(())
+}
+
+fn summary(args: SummaryArgs) -> Result<()> {
+    let rows: Vec<_> = days::DAYS
+        .iter()
+        .flat_map(|day| {
+            let source = Source::resolve(day.day, None);
+            let timings = (!source.is_missing()).then(|| {
+                source
+                    .read()
+                    .and_then(|input| day.timed(&input, &Part::ALL))
+            });
+            summary::rows(day, timings)
+        })
+        .collect();
+    println!("{}", summary::render(&rows, args.markdown));
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    let root = scaffold::workspace_root(&env::current_dir()?)?;
+    let dir = scaffold::new_day(&root, args.day)?;
+    println!("created {}", dir.display());
+    println!("registered it in aoc/Cargo.toml and aoc/src/days.rs");
+    Ok(())
+}
+
+fn dashboard() -> Result<()> {
+    let cwd = env::current_dir()?;
+    let registry = scaffold::workspace_root(&cwd)
+        .unwrap_or(cwd)
+        .join("answers.toml");
+    let known = answers::known(&registry, &Cache::from_env())?;
+    let app = App::new(&known, |day| !Source::resolve(day, None).is_missing());
+    tui::run(app)
+}
+
+fn watch(args: WatchArgs) -> Result<()> {
+    let root = scaffold::workspace_root(&env::current_dir()?)?;
+    watch::watch(&root, args.day)
+}
+
+fn generate(args: GenArgs) -> Result<()> {
+    print!("{}", days::get(args.day)?.generate(args.size, args.seed)?);
+    Ok(())
+}
+
+fn diff(args: DiffArgs) -> Result<()> {
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![days::get(day)?],
+        None => days::DAYS
+            .iter()
+            .filter(|day| day.oracle.is_some())
+            .collect(),
+    };
+    for day in days {
+        if let Some(mismatch) = differential::check(day, args.size, 0..args.seeds)? {
+            bail!("{mismatch}");
+        }
+        println!("day {}: no mismatch over {} inputs", day.day, args.seeds);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serve")]
+fn serve(args: ServeArgs) -> Result<()> {
+    let server = serve::bind(&args.addr)?;
+    println!("listening on http://{}", server.server_addr());
+    let limits = Limits {
+        max_body: args.max_body,
+        timeout: Duration::from_secs(args.timeout),
+        max_solvers: args.max_solvers,
+    };
+    serve::serve(server, limits, args.threads);
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Summary(args) => summary(args),
+        Command::New(args) => new(args),
+        Command::Tui => dashboard(),
+        Command::Watch(args) => watch(args),
+        Command::Gen(args) => generate(args),
+        Command::Diff(args) => diff(args),
+        #[cfg(feature = "serve")]
+        Command::Serve(args) => serve(args),
+    }
+}
@@ -0,0 +1,137 @@
+//! Allocation accounting, enabled by installing [`Counting`] as the global allocator
+//!
+//! The `alloc-count` feature installs it in the `aoc` binary. The counters are
+//! shared by every thread, so a solver spawning threads is accounted for as a whole.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Bytes currently allocated
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// Highest value of `CURRENT` since the last measure started
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator delegating to the system one, counting what goes through it
+pub struct Counting;
+
+impl Counting {
+    fn grow(size: usize) {
+        INSTALLED.store(true, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Counting::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Counting::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Count a reallocation as a new allocation of the new size, freeing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            Counting::grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running a phase of a solver
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    /// Number of allocations, reallocations included
+    pub count: usize,
+    /// Total size of the allocations, in bytes
+    pub bytes: usize,
+    /// Most memory in use at once, in bytes above what was in use before the phase
+    pub peak: usize,
+    /// Bytes still allocated at the end of the phase, such as the parsed input
+    pub retained: usize,
+}
+
+impl Allocations {
+    /// Allocations of this phase followed by the next one
+    pub fn then(self, next: Allocations) -> Allocations {
+        Allocations {
+            count: self.count + next.count,
+            bytes: self.bytes + next.bytes,
+            peak: self.peak.max(self.retained + next.peak),
+            retained: self.retained + next.retained,
+        }
+    }
+}
+
+/// Run `f`, counting its allocations, `None` when [`Counting`] is not the global
+/// allocator
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    let output = f();
+    let allocations = INSTALLED.load(Ordering::Relaxed).then(|| Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+        retained: CURRENT.load(Ordering::Relaxed).saturating_sub(current),
+    });
+    (output, allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_then() {
+        let parse = Allocations {
+            count: 2,
+            bytes: 100,
+            peak: 80,
+            retained: 60,
+        };
+        let solve = Allocations {
+            count: 3,
+            bytes: 50,
+            peak: 40,
+            retained: 0,
+        };
+        assert_eq!(
+            parse.then(solve),
+            Allocations {
+                count: 5,
+                bytes: 150,
+                peak: 100,
+                retained: 60,
+            }
+        );
+    }
+}
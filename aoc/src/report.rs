@@ -0,0 +1,39 @@
+use anyhow::Error;
+use aoc_common::diagnostic::Diagnostic;
+
+/// Render an error, with a snippet of the input when it points into it
+pub fn render(error: &Error, name: &str) -> String {
+    match error.chain().find_map(|e| e.downcast_ref::<Diagnostic>()) {
+        Some(diagnostic) => diagnostic.render(name),
+        None => format!("{error:#}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use aoc_common::Solver;
+    use day_2::Day02;
+
+    use super::*;
+
+    #[test]
+    fn test_render_diagnostic() {
+        let error = Day02::parse("Game 1: 3 blue\nGame 2; 4 red").unwrap_err();
+        assert_eq!(
+            render(&error, "day-02.txt"),
+            "error: invalid game: expected `: `
+ --> day-02.txt:2:7
+  |
+2 | Game 2; 4 red
+  |       ^
+  = help: games look like `Game 1: 3 blue, 4 red; 2 green`"
+        );
+    }
+
+    #[test]
+    fn test_render_other_error() {
+        let error = anyhow!("no start").context("solve part 1");
+        assert_eq!(render(&error, "day-02.txt"), "solve part 1: no start");
+    }
+}
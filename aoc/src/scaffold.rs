@@ -0,0 +1,381 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::input;
+
+use crate::cache;
+
+/// Template files that are not part of a generated day
+const TEMPLATE_ONLY: [&str; 1] = ["cargo-generate.toml"];
+
+/// Find the workspace root, starting at the given directory
+pub fn workspace_root(start: &Path) -> Result<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or(anyhow!("not inside the workspace: {}", start.display()))
+}
+
+/// Replace the template placeholders for the given day
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{project-name}}", &format!("day-{day:02}"))
+        .replace("{{crate_name}}", &format!("day_{day:02}"))
+        .replace("{{day_number}}", &day.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+}
+
+/// Add a member to the `members` list of the workspace manifest, keeping it sorted
+pub fn register_member(manifest: &str, member: &str) -> Result<String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or(anyhow!("missing workspace members"))?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or(anyhow!("unterminated workspace members"))?;
+    let entry = format!("\"{member}\"");
+    let mut lines: Vec<&str> = manifest[start..end].lines().collect();
+    if lines
+        .iter()
+        .any(|line| line.trim().trim_end_matches(',') == entry)
+    {
+        bail!("{member} is already a workspace member");
+    }
+
+    let line = format!("  {entry},");
+    let position = lines
+        .iter()
+        .skip(1)
+        .position(|l| l.trim() > line.trim())
+        .map(|p| p + 1)
+        .unwrap_or(lines.len());
+    lines.insert(position, &line);
+    Ok(format!(
+        "{}{}\n{}",
+        &manifest[..start],
+        lines.join("\n"),
+        &manifest[end..]
+    ))
+}
+
+/// Day of a dependency line such as `day_1 = …` or `day-03 = …`
+fn dependency_day(line: &str) -> Option<u8> {
+    let (name, _) = line.split_once('=')?;
+    name.trim()
+        .strip_prefix("day")?
+        .trim_start_matches(['-', '_'])
+        .parse()
+        .ok()
+}
+
+/// Add a day crate to the dependencies of the `aoc` manifest, keeping the days in order
+pub fn register_dependency(manifest: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .ok_or(anyhow!("missing dependencies"))?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with('['))
+        .map_or(lines.len(), |p| start + 1 + p);
+    let days: Vec<(usize, u8)> = (start + 1..end)
+        .filter_map(|i| Some((i, dependency_day(lines[i])?)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        bail!("day {day} is already a dependency");
+    }
+
+    let line = format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}");
+    let position = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map(|&(i, _)| i)
+        .or(days.last().map(|&(i, _)| i + 1))
+        .unwrap_or(start + 1);
+    lines.insert(position, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add a day to the `DAYS` registry of `aoc/src/days.rs`, keeping it in order
+pub fn register_day(source: &str, day: u8) -> Result<String> {
+    const HEADER: &str = "pub const DAYS: [Day; ";
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(HEADER))
+        .ok_or(anyhow!("missing the DAYS registry"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or(anyhow!("unterminated DAYS registry"))?;
+    let count: usize = lines[start][HEADER.len()..]
+        .split_once(']')
+        .and_then(|(count, _)| count.parse().ok())
+        .ok_or(anyhow!("missing the length of the DAYS registry"))?;
+    // Each entry opens with `Day {` on the line before its `day: N,`
+    let days: Vec<(usize, u8)> = (start + 1..end)
+        .filter_map(|i| {
+            let day = lines[i].trim().strip_prefix("day: ")?;
+            Some((i - 1, day.trim_end_matches(',').parse().ok()?))
+        })
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        bail!("day {day} is already registered");
+    }
+
+    let name = format!("day_{day:02}");
+    let entry = [
+        "    Day {".to_string(),
+        format!("        day: {day},"),
+        format!("        part1: {name}::part1::process,"),
+        format!("        part2: {name}::part2::process,"),
+        format!("        timed: timed::<{name}::Day{day:02}>,"),
+        "        generate: None,".to_string(),
+        "        oracle: None,".to_string(),
+        "    },".to_string(),
+    ];
+    let position = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(end, |&(i, _)| i);
+    lines.splice(position..position, entry);
+    lines[start] = format!("{HEADER}{}] = [", count + 1);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Read a file of the workspace, apply an edit to it and return the edited content
+fn edit(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<String> {
+    let content = fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    edit(&content).with_context(|| format!("edit {}", path.display()))
+}
+
+/// Copy the template into a new day crate, register it in the workspace and in the
+/// `aoc` runner
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {day}");
+    }
+    let name = format!("day-{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    // Every edit is checked before anything is written
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = edit(&manifest_path, |manifest| register_member(manifest, &name))?;
+    let aoc_manifest_path = root.join("aoc/Cargo.toml");
+    let aoc_manifest = edit(&aoc_manifest_path, |manifest| {
+        register_dependency(manifest, day)
+    })?;
+    let days_path = root.join("aoc/src/days.rs");
+    let days = edit(&days_path, |source| register_day(source, day))?;
+
+    copy_template(&root.join("template"), &dir, day)?;
+    cache::write(&manifest_path, &manifest)?;
+    cache::write(&aoc_manifest_path, &aoc_manifest)?;
+    cache::write(&days_path, &days)?;
+
+    let input = input::day_path(&input::input_dir_in(root), day);
+    if !input.exists() {
+        cache::write(&input, "")?;
+    }
+    Ok(dir)
+}
+
+fn copy_template(template: &Path, dest: &Path, day: u8) -> Result<()> {
+    let entries = fs::read_dir(template).with_context(|| format!("read {}", template.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&path, &target, day)?;
+        } else if !TEMPLATE_ONLY.contains(&entry.file_name().to_string_lossy().as_ref()) {
+            let content =
+                fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+            cache::write(&target, &render(&content, day))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+resolver = "2"
+members = [
+  "aoc",
+  "common",
+  "day-01",
+  "day-11",
+]
+
+[workspace.dependencies]
+anyhow = "1.0.75"
+"#;
+
+    const AOC_MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+anyhow = { workspace = true }
+day_1 = { path = "../day-01" }
+day-03 = { path = "../day-03" }
+day-11 = { path = "../day-11" }
+notify = "6.1.1"
+
+[dev-dependencies]
+day-20 = { path = "../day-20" }
+"#;
+
+    const DAYS: &str = r#"use anyhow::Result;
+
+pub const DAYS: [Day; 2] = [
+    Day {
+        day: 1,
+        part1: day_1::part1::process,
+        part2: day_1::part2::process,
+        timed: timed::<day_1::Day01>,
+        generate: None,
+        oracle: None,
+    },
+    Day {
+        day: 11,
+        part1: day_11::part1::process,
+        part2: day_11::part2::process,
+        timed: timed::<day_11::Day11>,
+        generate: Some(day_11::generate::generate),
+        oracle: None,
+    },
+];
+
+pub fn get() {}
+"#;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "name = \"{{project-name}}\"\nuse {{crate_name}}::Day{{day}};\nDAY = {{day_number}};",
+                7
+            ),
+            "name = \"day-07\"\nuse day_07::Day07;\nDAY = 7;"
+        );
+    }
+
+    #[test]
+    fn test_register_member() -> Result<()> {
+        let manifest = register_member(MANIFEST, "day-05")?;
+        assert!(manifest.contains("  \"day-01\",\n  \"day-05\",\n  \"day-11\",\n]"));
+        let manifest = register_member(&manifest, "day-12")?;
+        assert!(manifest.contains("  \"day-11\",\n  \"day-12\",\n]\n\n[workspace.dependencies]"));
+        assert!(register_member(&manifest, "day-12").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_dependency() -> Result<()> {
+        let manifest = register_dependency(AOC_MANIFEST, 2)?;
+        assert!(manifest.contains(
+            "day_1 = { path = \"../day-01\" }\nday-02 = { path = \"../day-02\" }\nday-03 ="
+        ));
+        let manifest = register_dependency(&manifest, 12)?;
+        assert!(manifest.contains(
+            "day-11 = { path = \"../day-11\" }\nday-12 = { path = \"../day-12\" }\nnotify"
+        ));
+        assert!(register_dependency(&manifest, 3).is_err());
+        // Days of other sections are left alone
+        assert!(register_dependency(&manifest, 20)?
+            .contains("day-20 = { path = \"../day-20\" }\nnotify"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_day() -> Result<()> {
+        let days = register_day(DAYS, 12)?;
+        assert!(days.starts_with("use anyhow::Result;\n\npub const DAYS: [Day; 3] = ["));
+        assert!(days.ends_with(
+            "        oracle: None,
+    },
+    Day {
+        day: 12,
+        part1: day_12::part1::process,
+        part2: day_12::part2::process,
+        timed: timed::<day_12::Day12>,
+        generate: None,
+        oracle: None,
+    },
+];
+
+pub fn get() {}
+"
+        ));
+        let days = register_day(&days, 5)?;
+        assert!(days.contains("[Day; 4]"));
+        assert!(days.contains("day: 1,"));
+        let five = days.find("day: 5,").unwrap();
+        assert!(days.find("day: 1,").unwrap() < five);
+        assert!(five < days.find("day: 11,").unwrap());
+        assert!(register_day(&days, 11).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_root() -> Result<()> {
+        let root = workspace_root(Path::new(env!("CARGO_MANIFEST_DIR")))?;
+        assert!(root.join("template").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day() -> Result<()> {
+        let root = env::temp_dir().join("aoc-test-new-day");
+        let _ = fs::remove_dir_all(&root);
+        cache::write(&root.join("Cargo.toml"), MANIFEST)?;
+        cache::write(&root.join("aoc/Cargo.toml"), AOC_MANIFEST)?;
+        cache::write(&root.join("aoc/src/days.rs"), DAYS)?;
+        cache::write(
+            &root.join("template/src/lib.rs"),
+            "pub struct Day{{day}};\n",
+        )?;
+        cache::write(&root.join("template/cargo-generate.toml"), "")?;
+        cache::write(&root.join("template/example.txt"), "")?;
+
+        let dir = new_day(&root, 12)?;
+        assert_eq!(dir, root.join("day-12"));
+        assert_eq!(
+            fs::read_to_string(dir.join("src/lib.rs"))?,
+            "pub struct Day12;\n"
+        );
+        assert!(dir.join("example.txt").exists());
+        assert!(!dir.join("cargo-generate.toml").exists());
+        assert!(fs::read_to_string(root.join("Cargo.toml"))?.contains("\"day-12\""));
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))?.contains("day-12 = "));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))?.contains("day: 12,"));
+
+        assert!(new_day(&root, 12).is_err());
+
+        // Nothing is written when the runner cannot be edited
+        cache::write(&root.join("aoc/src/days.rs"), "")?;
+        assert!(new_day(&root, 13).is_err());
+        assert!(!root.join("day-13").exists());
+        assert!(!fs::read_to_string(root.join("Cargo.toml"))?.contains("\"day-13\""));
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
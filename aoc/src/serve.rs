@@ -0,0 +1,381 @@
+use std::{
+    io::Read,
+    panic::UnwindSafe,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    days::{self, Part},
+    json,
+};
+
+/// Largest input accepted by default, well above the size of any puzzle input
+pub const DEFAULT_MAX_BODY: usize = 1 << 20;
+
+/// Solvers running at once by default, timed out ones included
+pub const DEFAULT_MAX_SOLVERS: usize = 8;
+
+/// Limits applied to every request
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Largest accepted input, in bytes
+    pub max_body: usize,
+    /// Time a part may run before the request fails
+    pub timeout: Duration,
+    /// Solvers running at once, including the ones whose request timed out
+    pub max_solvers: usize,
+}
+
+/// Count of the running solvers, shared by every request
+#[derive(Debug, Clone, Default)]
+pub struct Solvers(Arc<AtomicUsize>);
+
+/// Place of a running solver, freed when dropped
+#[derive(Debug)]
+struct Slot(Arc<AtomicUsize>);
+
+impl Solvers {
+    /// Take a place for a solver, unless `max` of them are already running
+    fn acquire(&self, max: usize) -> Option<Slot> {
+        self.0
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < max).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(&self.0)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Status and JSON body of a response
+#[derive(Debug, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Reply {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Reply {
+            status,
+            body: serde_json::json!({ "error": message.into() }).to_string(),
+        }
+    }
+}
+
+/// Split `/day/{n}/part/{p}` into its day and part
+fn route(url: &str) -> Option<(&str, &str)> {
+    let path = url.split('?').next()?;
+    match path.trim_matches('/').split('/').collect::<Vec<_>>()[..] {
+        ["day", day, "part", part] => Some((day, part)),
+        _ => None,
+    }
+}
+
+/// Read the input, refusing it if it is larger than `max` bytes
+fn read_input(body: impl Read, length: Option<usize>, max: usize) -> Result<String, Reply> {
+    let too_large = || Reply::error(413, format!("input is larger than {max} bytes"));
+    if length.is_some_and(|length| length > max) {
+        return Err(too_large());
+    }
+    let mut bytes = vec![];
+    body.take(max as u64 + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| Reply::error(400, format!("read input: {e}")))?;
+    if bytes.len() > max {
+        return Err(too_large());
+    }
+    String::from_utf8(bytes).map_err(|_| Reply::error(400, "input is not valid UTF-8"))
+}
+
+/// Run `f` in the background, giving up on it after `timeout`
+///
+/// A solver cannot be interrupted, so one that times out keeps running until it
+/// finishes and its result is dropped. One that panics gives back the panic as an error.
+fn within<T: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> T + Send + UnwindSafe + 'static,
+) -> Option<Result<T>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        // The request may have timed out in the meantime
+        let _ = tx.send(days::catch_panic(|| Ok(f())));
+    });
+    rx.recv_timeout(timeout).ok()
+}
+
+/// Answer a request for `POST /day/{n}/part/{p}` with the run of that part as JSON
+pub fn handle(
+    method: &Method,
+    url: &str,
+    length: Option<usize>,
+    body: impl Read,
+    limits: &Limits,
+    solvers: &Solvers,
+) -> Reply {
+    let Some((day, part)) = route(url) else {
+        return Reply::error(
+            404,
+            format!("no route for {url}, expected /day/{{n}}/part/{{p}}"),
+        );
+    };
+    if *method != Method::Post {
+        return Reply::error(405, format!("{method} is not allowed, use POST"));
+    }
+    let day = match day
+        .parse()
+        .map_err(|_| anyhow!("invalid day: {day}"))
+        .and_then(days::get)
+    {
+        Ok(day) => day,
+        Err(e) => return Reply::error(404, e.to_string()),
+    };
+    let part: Part = match part.parse() {
+        Ok(part) => part,
+        Err(e) => return Reply::error(404, format!("{e}")),
+    };
+    let input = match read_input(body, length, limits.max_body) {
+        Ok(input) => input,
+        Err(reply) => return reply,
+    };
+
+    // The place is only freed once the solver finishes, even after a timeout
+    let Some(slot) = solvers.acquire(limits.max_solvers) else {
+        return Reply::error(
+            503,
+            format!(
+                "{} solvers are already running, try again later",
+                limits.max_solvers
+            ),
+        );
+    };
+    let run = move || {
+        let _slot = slot;
+        json::runs(day, Ok(input), &[part])
+    };
+    let mut runs = match within(limits.timeout, run) {
+        Some(Ok(runs)) => runs,
+        Some(Err(e)) => return Reply::error(500, e.to_string()),
+        None => {
+            return Reply::error(
+                504,
+                format!(
+                    "day {} part {part} did not finish within {:?}",
+                    day.day, limits.timeout
+                ),
+            )
+        }
+    };
+    let run = runs.remove(0);
+    Reply {
+        status: if run.error.is_some() { 422 } else { 200 },
+        body: serde_json::to_string(&run).expect("a run is always serializable"),
+    }
+}
+
+fn respond(mut request: Request, limits: &Limits, solvers: &Solvers) {
+    let method = request.method().clone();
+    let url = request.url().to_string();
+    let length = request.body_length();
+    let reply = handle(&method, &url, length, request.as_reader(), limits, solvers);
+    eprintln!("{method} {url} {}", reply.status);
+
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json").expect("a static header is valid");
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+    if let Err(e) = request.respond(response) {
+        eprintln!("{method} {url}: {e}");
+    }
+}
+
+/// Listen on the address, `127.0.0.1:0` picking a free port
+pub fn bind(addr: &str) -> Result<Server> {
+    Server::http(addr).map_err(|e| anyhow!("listen on {addr}: {e}"))
+}
+
+/// Answer requests on `threads` threads until the process is stopped
+pub fn serve(server: Server, limits: Limits, threads: usize) {
+    let server = Arc::new(server);
+    let solvers = Solvers::default();
+    let workers: Vec<_> = (0..threads.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let solvers = solvers.clone();
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, &limits, &solvers);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+    const LIMITS: Limits = Limits {
+        max_body: 64,
+        timeout: Duration::from_secs(10),
+        max_solvers: 2,
+    };
+
+    fn post(url: &str, input: &str) -> (u16, Value) {
+        let reply = handle(
+            &Method::Post,
+            url,
+            None,
+            input.as_bytes(),
+            &LIMITS,
+            &Solvers::default(),
+        );
+        (reply.status, serde_json::from_str(&reply.body).unwrap())
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("/day/5/part/2"), Some(("5", "2")));
+        assert_eq!(route("/day/5/part/2/?pretty"), Some(("5", "2")));
+        assert_eq!(route("/day/5"), None);
+        assert_eq!(route("/"), None);
+    }
+
+    #[test]
+    fn test_handle() {
+        let (status, run) = post("/day/9/part/1", INPUT);
+        assert_eq!(status, 200);
+        assert_eq!(run["answer"], "114");
+        assert_eq!(run["part"], 1);
+        assert!(run["solve_ns"].is_u64());
+
+        let (status, run) = post("/day/2/part/1", "Game 1; 3 blue");
+        assert_eq!(status, 422);
+        assert_eq!(run["error"]["message"], "invalid game: expected `: `");
+        assert_eq!(run["error"]["column"], 7);
+    }
+
+    #[test]
+    fn test_handle_rejected() {
+        assert_eq!(post("/day/25/part/1", INPUT).0, 404);
+        assert_eq!(post("/day/9/part/3", INPUT).0, 404);
+        assert_eq!(post("/days", INPUT).0, 404);
+        let (status, error) = post("/day/9/part/1", &"1 ".repeat(40));
+        assert_eq!(status, 413);
+        assert_eq!(error["error"], "input is larger than 64 bytes");
+
+        let reply = handle(
+            &Method::Get,
+            "/day/9/part/1",
+            None,
+            INPUT.as_bytes(),
+            &LIMITS,
+            &Solvers::default(),
+        );
+        assert_eq!(reply.status, 405);
+        let reply = handle(
+            &Method::Post,
+            "/day/9/part/1",
+            Some(100),
+            INPUT.as_bytes(),
+            &LIMITS,
+            &Solvers::default(),
+        );
+        assert_eq!(reply.status, 413);
+    }
+
+    #[test]
+    fn test_within() {
+        assert_eq!(within(Duration::from_secs(10), || 42).unwrap().unwrap(), 42);
+        let slow = || thread::sleep(Duration::from_secs(10));
+        assert!(within(Duration::from_millis(10), slow).is_none());
+        let error = within(Duration::from_secs(10), || panic!("no way"))
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "solver panicked: no way");
+    }
+
+    #[test]
+    fn test_handle_busy() {
+        let solvers = Solvers::default();
+        let post = || {
+            let reply = handle(
+                &Method::Post,
+                "/day/9/part/1",
+                None,
+                INPUT.as_bytes(),
+                &LIMITS,
+                &solvers,
+            );
+            reply.status
+        };
+        let slots: Vec<_> = (0..2).map(|_| solvers.acquire(2).unwrap()).collect();
+        assert_eq!(post(), 503);
+        drop(slots);
+        assert_eq!(post(), 200);
+        assert_eq!(solvers.0.load(Ordering::SeqCst), 0);
+
+        // A timed out solver keeps its place until it finishes
+        let limits = Limits {
+            timeout: Duration::from_millis(10),
+            ..LIMITS
+        };
+        let slot = solvers.acquire(2).unwrap();
+        let slow = move || {
+            let _slot = slot;
+            thread::sleep(Duration::from_millis(200))
+        };
+        assert!(within(limits.timeout, slow).is_none());
+        assert_eq!(solvers.0.load(Ordering::SeqCst), 1);
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(solvers.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_handle_panic() {
+        let (status, error) = post("/day/8/part/1", "L\n\nAAA = (BBB, BBB)\n");
+        assert_eq!(status, 500);
+        assert!(error["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("solver panicked: "));
+    }
+
+    #[test]
+    fn test_serve() -> Result<()> {
+        let server = bind("127.0.0.1:0")?;
+        let url = format!("http://{}/day/9/part/2", server.server_addr());
+        thread::spawn(move || serve(server, LIMITS, 1));
+
+        let response = ureq::post(&url).send_string(INPUT)?;
+        assert_eq!(response.header("Content-Type"), Some("application/json"));
+        let run: Value = serde_json::from_str(&response.into_string()?)?;
+        assert_eq!(run["answer"], "2");
+        match ureq::get(&url).call() {
+            Err(ureq::Error::Status(status, _)) => assert_eq!(status, 405),
+            other => panic!("expected an error status, got {other:?}"),
+        }
+        Ok(())
+    }
+}
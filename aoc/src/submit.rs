@@ -0,0 +1,349 @@
+use std::{fmt, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Answer;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    cache::{self, Cache},
+    client::{self, Client},
+    days::Part,
+};
+
+/// Outcome of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too recently, with the number of seconds left to wait
+    Wait(u64),
+    /// The part was already solved
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Parse the verdict out of the response page
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(page).unwrap_or(60))
+        } else if page.contains("solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait(seconds) => write!(f, "submitted too recently, wait {seconds}s"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Parse the `You have 1m 30s left to wait` notice, in seconds
+fn parse_wait(page: &str) -> Option<u64> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (delay, _) = rest.split_once(" left to wait")?;
+    delay.split_whitespace().try_fold(0, |total, unit| {
+        match unit.split_at(unit.len().checked_sub(1)?) {
+            (n, "m") => Some(total + n.parse::<u64>().ok()? * 60),
+            (n, "s") => Some(total + n.parse::<u64>().ok()?),
+            _ => None,
+        }
+    })
+}
+
+/// Parse the delay imposed after a wrong answer, in seconds
+fn parse_cooldown(page: &str) -> Option<u64> {
+    let (_, rest) = page.split_once("lease wait ")?;
+    let (delay, _) = rest.split_once(" before trying again")?;
+    match delay.split_once(' ')? {
+        ("one", "minute") => Some(60),
+        (n, "minutes") => Some(n.parse::<u64>().ok()? * 60),
+        (n, "seconds") => n.parse().ok(),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    #[serde(
+        serialize_with = "serialize_answer",
+        deserialize_with = "deserialize_answer"
+    )]
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Unix time of the submission, in seconds
+    pub submitted_at: u64,
+    /// Unix time before which no other answer is accepted, in seconds
+    pub retry_at: Option<u64>,
+}
+
+/// Store answers as the text that was submitted
+fn serialize_answer<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(answer)
+}
+
+fn deserialize_answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Answer, D::Error> {
+    let answer = String::deserialize(deserializer)?;
+    Ok(answer.parse().unwrap_or_else(|e| match e {}))
+}
+
+/// Local log of every submitted answer
+#[derive(Debug, Default)]
+pub struct AnswerLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl AnswerLog {
+    /// Load the log stored in the cache, starting a new one when missing
+    pub fn open(cache: &Cache) -> Result<Self> {
+        let path = cache.dir().join("answers.json");
+        let attempts = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("parse answer log {}", path.display()))?,
+            Err(_) => vec![],
+        };
+        Ok(AnswerLog { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        self.attempts.push(attempt);
+        cache::write(&self.path, &serde_json::to_string_pretty(&self.attempts)?)
+    }
+
+    /// Return the attempts of a part
+    fn of(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.day == day && a.part == part)
+    }
+
+    /// Refuse answers that are known to be wrong, or that cannot be submitted yet
+    pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<()> {
+        if let Some(correct) = self.of(day, part).find(|a| a.verdict == Verdict::Correct) {
+            bail!(
+                "day {day} part {part} is already solved with {}",
+                correct.answer
+            );
+        }
+        if let Some(wrong) = self
+            .of(day, part)
+            .find(|a| a.answer == *answer && a.verdict.is_wrong())
+        {
+            bail!("{answer} was already submitted and is {}", wrong.verdict);
+        }
+        if let Some(retry_at) = self.of(day, part).filter_map(|a| a.retry_at).max() {
+            if now < retry_at {
+                bail!("wait {}s before submitting again", retry_at - now);
+            }
+        }
+
+        for attempt in self.of(day, part) {
+            let bound = &attempt.answer;
+            match attempt.verdict {
+                Verdict::TooHigh if answer >= bound => {
+                    bail!("{answer} is too high, {bound} was already too high")
+                }
+                Verdict::TooLow if answer <= bound => {
+                    bail!("{answer} is too low, {bound} was already too low")
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submit an answer unless the log already knows it is wrong, and record the attempt
+pub fn submit(
+    client: &Client,
+    log: &mut AnswerLog,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Attempt> {
+    let now = client::now()?.as_secs();
+    log.check(day, part, answer, now)?;
+    let page = client.answer(day, part, &answer.to_string())?;
+    let verdict = Verdict::parse(&page);
+    let retry_at = match verdict {
+        Verdict::Wait(seconds) => Some(now + seconds),
+        _ => parse_cooldown(&page).map(|seconds| now + seconds),
+    };
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.clone(),
+        verdict,
+        submitted_at: now,
+        retry_at,
+    };
+    log.record(attempt.clone())?;
+    Ok(attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. \
+        Please wait one minute before trying again.</p></article>";
+
+    fn attempt(answer: u64, verdict: Verdict) -> Attempt {
+        Attempt {
+            day: 1,
+            part: Part::One,
+            answer: answer.into(),
+            verdict,
+            submitted_at: 0,
+            retry_at: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            Verdict::parse("<p>That's the right answer! You are one gold star closer.</p>"),
+            Verdict::Correct
+        );
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 1m 30s left to wait."),
+            Verdict::Wait(90)
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            Verdict::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn test_parse_cooldown() {
+        assert_eq!(parse_cooldown(TOO_HIGH), Some(60));
+        assert_eq!(
+            parse_cooldown("Please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(parse_cooldown("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn test_check_known_wrong() {
+        let log = AnswerLog {
+            attempts: vec![attempt(42, Verdict::Incorrect)],
+            ..Default::default()
+        };
+        assert!(log.check(1, Part::One, &42.into(), 0).is_err());
+        assert!(log.check(1, Part::One, &43.into(), 0).is_ok());
+        assert!(log.check(1, Part::Two, &42.into(), 0).is_ok());
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let log = AnswerLog {
+            attempts: vec![attempt(100, Verdict::TooHigh), attempt(10, Verdict::TooLow)],
+            ..Default::default()
+        };
+        assert!(log.check(1, Part::One, &150.into(), 0).is_err());
+        assert!(log.check(1, Part::One, &100.into(), 0).is_err());
+        assert!(log.check(1, Part::One, &5.into(), 0).is_err());
+        assert!(log.check(1, Part::One, &50.into(), 0).is_ok());
+    }
+
+    #[test]
+    fn test_check_wait() {
+        let mut wait = attempt(42, Verdict::Wait(30));
+        wait.retry_at = Some(130);
+        let log = AnswerLog {
+            attempts: vec![wait],
+            ..Default::default()
+        };
+        assert!(log.check(1, Part::One, &43.into(), 100).is_err());
+        assert!(log.check(1, Part::One, &43.into(), 130).is_ok());
+    }
+
+    #[test]
+    fn test_check_solved() {
+        let log = AnswerLog {
+            attempts: vec![attempt(42, Verdict::Correct)],
+            ..Default::default()
+        };
+        assert!(log.check(1, Part::One, &43.into(), 0).is_err());
+    }
+
+    #[test]
+    fn test_submit() -> Result<()> {
+        let server = MockServer::start(vec![(200, TOO_HIGH.to_string())]);
+        let cache = Cache::new(env::temp_dir().join("aoc-test-submit"));
+        let _ = fs::remove_dir_all(cache.dir());
+        let client =
+            Client::new(&server.url(), Some("secret"), cache.clone()).min_interval(Duration::ZERO);
+
+        let mut log = AnswerLog::open(&cache)?;
+        let attempt = submit(&client, &mut log, 1, Part::One, &142.into())?;
+        assert_eq!(attempt.verdict, Verdict::TooHigh);
+        assert!(attempt.retry_at.is_some());
+
+        let log = AnswerLog::open(&cache)?;
+        assert_eq!(log.attempts(), [attempt]);
+        assert!(submit(
+            &client,
+            &mut AnswerLog::open(&cache)?,
+            1,
+            Part::One,
+            &142.into()
+        )
+        .is_err());
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+}
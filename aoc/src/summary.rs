@@ -0,0 +1,264 @@
+use std::time::Duration;
+
+use crate::{
+    days::{Day, Part, Timings},
+    memory::Allocations,
+};
+
+/// A line of the summary table
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub answer: String,
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+    /// Allocations of the parse followed by the solve, when they are counted
+    pub allocations: Option<Allocations>,
+}
+
+/// Turn the run of a day, or why it could not run, into summary rows.
+///
+/// Days without timings are the ones whose input is missing.
+pub fn rows(day: &Day, timings: Option<anyhow::Result<Timings>>) -> Vec<Row> {
+    match timings {
+        None => vec![Row {
+            day: day.day,
+            part: None,
+            answer: "missing input".into(),
+            parse: None,
+            solve: None,
+            allocations: None,
+        }],
+        Some(Ok(timings)) => timings
+            .parts
+            .into_iter()
+            .map(|part| Row {
+                day: day.day,
+                part: Some(part.part),
+                answer: match part.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => format!("error: {e}"),
+                },
+                parse: Some(timings.parse),
+                solve: Some(part.solve),
+                allocations: timings
+                    .parse_allocations
+                    .zip(part.allocations)
+                    .map(|(parse, solve)| parse.then(solve)),
+            })
+            .collect(),
+        Some(Err(e)) => vec![Row {
+            day: day.day,
+            part: None,
+            answer: format!("error: {e}"),
+            parse: None,
+            solve: None,
+            allocations: None,
+        }],
+    }
+}
+
+/// Format a duration with a unit fitting its magnitude
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    match micros {
+        0..=999 => format!("{micros}µs"),
+        1_000..=999_999 => format!("{:.2}ms", micros as f64 / 1e3),
+        _ => format!("{:.2}s", micros as f64 / 1e6),
+    }
+}
+
+/// Format a number of bytes with a binary unit fitting its magnitude
+pub fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes}B"),
+        1024..=1_048_575 => format!("{:.2}KiB", bytes as f64 / 1024.0),
+        1_048_576..=1_073_741_823 => format!("{:.2}MiB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.2}GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+/// Total wall time of the season, counting each parse once per day
+pub fn total(rows: &[Row]) -> Duration {
+    let mut total = Duration::ZERO;
+    for (i, row) in rows.iter().enumerate() {
+        let first_of_day = i == 0 || rows[i - 1].day != row.day;
+        if first_of_day {
+            total += row.parse.unwrap_or_default();
+        }
+        total += row.solve.unwrap_or_default();
+    }
+    total
+}
+
+/// Render the summary as an aligned text table, or as a markdown table
+///
+/// The allocation columns are only shown when the allocations were counted.
+pub fn render(rows: &[Row], markdown: bool) -> String {
+    let counted = rows.iter().any(|row| row.allocations.is_some());
+    let mut header = vec!["Day", "Part", "Answer", "Parse", "Solve"];
+    if counted {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+    let columns = header.len();
+    let format_time = |time: Option<Duration>| time.map(format_duration).unwrap_or("-".into());
+    let mut lines: Vec<Vec<String>> = vec![header.into_iter().map(String::from).collect()];
+    lines.extend(rows.iter().map(|row| {
+        let mut line = vec![
+            format!("{:02}", row.day),
+            row.part.map(|p| p.to_string()).unwrap_or("-".into()),
+            row.answer.clone(),
+            format_time(row.parse),
+            format_time(row.solve),
+        ];
+        if counted {
+            line.extend(match row.allocations {
+                Some(allocations) => [
+                    allocations.count.to_string(),
+                    format_bytes(allocations.bytes),
+                    format_bytes(allocations.peak),
+                ],
+                None => ["-".into(), "-".into(), "-".into()],
+            });
+        }
+        line
+    }));
+    let mut total_line = vec![String::new(); columns];
+    total_line[0] = "Total".into();
+    total_line[4] = format_duration(total(rows));
+    lines.push(total_line);
+
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            lines
+                .iter()
+                .map(|line| line[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_line = |line: &Vec<String>| {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        match markdown {
+            true => format!("| {} |", cells.join(" | ")),
+            false => cells.join("  ").trim_end().to_string(),
+        }
+    };
+
+    let mut output = vec![format_line(&lines[0])];
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    output.push(match markdown {
+        true => format!("| {} |", separator.join(" | ")),
+        false => separator.join("  "),
+    });
+    output.extend(lines[1..lines.len() - 1].iter().map(format_line));
+    if !markdown {
+        output.push(separator.join("  "));
+    }
+    output.push(format_line(&lines[lines.len() - 1]));
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 9,
+                part: Some(Part::One),
+                answer: "114".into(),
+                parse: Some(Duration::from_micros(10)),
+                solve: Some(Duration::from_micros(5)),
+                allocations: None,
+            },
+            Row {
+                day: 9,
+                part: Some(Part::Two),
+                answer: "2".into(),
+                parse: Some(Duration::from_micros(10)),
+                solve: Some(Duration::from_micros(7)),
+                allocations: None,
+            },
+            Row {
+                day: 10,
+                part: None,
+                answer: "missing input".into(),
+                parse: None,
+                solve: None,
+                allocations: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(12)), "12µs");
+        assert_eq!(format_duration(Duration::from_micros(1_234)), "1.23ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 << 20), "3.00MiB");
+    }
+
+    #[test]
+    fn test_total() {
+        assert_eq!(total(&rows()), Duration::from_micros(22));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(&rows(), false),
+            "Day    Part  Answer         Parse  Solve
+-----  ----  -------------  -----  -----
+09     1     114            10µs   5µs
+09     2     2              10µs   7µs
+10     -     missing input  -      -
+-----  ----  -------------  -----  -----
+Total                              22µs"
+        );
+    }
+
+    #[test]
+    fn test_render_allocations() {
+        let mut rows = rows();
+        rows[0].allocations = Some(Allocations {
+            count: 12,
+            bytes: 2048,
+            peak: 1536,
+            retained: 0,
+        });
+        assert_eq!(
+            render(&rows[..1], false),
+            "Day    Part  Answer  Parse  Solve  Allocs  Allocated  Peak
+-----  ----  ------  -----  -----  ------  ---------  -------
+09     1     114     10µs   5µs    12      2.00KiB    1.50KiB
+-----  ----  ------  -----  -----  ------  ---------  -------
+Total                       15µs"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(
+            render(&rows(), true),
+            "| Day   | Part | Answer        | Parse | Solve |
+| ----- | ---- | ------------- | ----- | ----- |
+| 09    | 1    | 114           | 10µs  | 5µs   |
+| 09    | 2    | 2             | 10µs  | 7µs   |
+| 10    | -    | missing input | -     | -     |
+| Total |      |               |       | 22µs  |"
+        );
+    }
+}
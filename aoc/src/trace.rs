@@ -0,0 +1,253 @@
+//! Recording of the spans of a run, printed as a tree or written as a Chrome trace
+
+use std::{
+    fmt,
+    fs::File,
+    io::BufWriter,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Subscriber,
+};
+use tracing_chrome::ChromeLayerBuilder;
+use tracing_subscriber::{layer, prelude::*, registry::LookupSpan, Layer, Registry};
+
+/// Span or event of the tree
+#[derive(Debug)]
+struct Node {
+    /// Name and fields, such as `day{day=5}` or `loop length=140`
+    label: String,
+    start: Instant,
+    /// Time from the creation of the span to its closing, `None` for events
+    elapsed: Option<Duration>,
+    children: Vec<usize>,
+}
+
+#[derive(Debug, Default)]
+struct Nodes {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+/// Index of the node of a span, kept in the extensions of the span
+struct Index(usize);
+
+/// Message and `key=value` fields of a span or an event
+#[derive(Default)]
+struct Fields {
+    message: Option<String>,
+    fields: Vec<String>,
+}
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = Some(format!("{value:?}")),
+            name => self.fields.push(format!("{name}={value:?}")),
+        }
+    }
+}
+
+/// Layer recording every span and event of a run, displayed as a tree with the
+/// time spent in each span
+#[derive(Debug, Clone, Default)]
+pub struct Tree(Arc<Mutex<Nodes>>);
+
+impl Tree {
+    fn push(&self, parent: Option<usize>, label: String) -> usize {
+        let mut nodes = self.0.lock().expect("the tree not to be poisoned");
+        let index = nodes.nodes.len();
+        nodes.nodes.push(Node {
+            label,
+            start: Instant::now(),
+            elapsed: None,
+            children: vec![],
+        });
+        match parent {
+            Some(parent) => nodes.nodes[parent].children.push(index),
+            None => nodes.roots.push(index),
+        }
+        index
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Tree {
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: layer::Context<'_, S>) {
+        let mut fields = Fields::default();
+        attrs.record(&mut fields);
+        let name = attrs.metadata().name();
+        let label = match fields.fields.is_empty() {
+            true => name.to_string(),
+            false => format!("{name}{{{}}}", fields.fields.join(" ")),
+        };
+        let span = ctx.span(id).expect("a new span to be registered");
+        let parent = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<Index>().map(|index| index.0));
+        let index = self.push(parent, label);
+        span.extensions_mut().insert(Index(index));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: layer::Context<'_, S>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let label: Vec<_> = fields.message.into_iter().chain(fields.fields).collect();
+        let parent = ctx
+            .event_span(event)
+            .and_then(|span| span.extensions().get::<Index>().map(|index| index.0));
+        self.push(parent, label.join(" "));
+    }
+
+    fn on_close(&self, id: span::Id, ctx: layer::Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else { return };
+        let index = span.extensions().get::<Index>().map(|index| index.0);
+        if let Some(index) = index {
+            let mut nodes = self.0.lock().expect("the tree not to be poisoned");
+            let node = &mut nodes.nodes[index];
+            node.elapsed = Some(node.start.elapsed());
+        }
+    }
+}
+
+fn write_node(
+    f: &mut fmt::Formatter<'_>,
+    nodes: &[Node],
+    index: usize,
+    prefix: &str,
+    branch: &str,
+) -> fmt::Result {
+    let node = &nodes[index];
+    match node.elapsed {
+        Some(elapsed) => writeln!(f, "{prefix}{branch}{} {elapsed:.2?}", node.label)?,
+        None => writeln!(f, "{prefix}{branch}{}", node.label)?,
+    }
+    let prefix = match branch {
+        "├─ " => format!("{prefix}│  "),
+        "└─ " => format!("{prefix}   "),
+        _ => prefix.to_string(),
+    };
+    for (i, &child) in node.children.iter().enumerate() {
+        let branch = if i + 1 == node.children.len() {
+            "└─ "
+        } else {
+            "├─ "
+        };
+        write_node(f, nodes, child, &prefix, branch)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes = self.0.lock().expect("the tree not to be poisoned");
+        for &root in &nodes.roots {
+            write_node(f, &nodes.nodes, root, "", "")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run `f`, recording the spans it enters on this thread as a tree
+pub fn tree<T>(f: impl FnOnce() -> T) -> (T, Tree) {
+    let tree = Tree::default();
+    let subscriber = Registry::default().with(tree.clone());
+    (tracing::subscriber::with_default(subscriber, f), tree)
+}
+
+/// Run `f`, writing the spans it enters on this thread to a Chrome trace, which
+/// `chrome://tracing` or Perfetto show as a flame graph
+pub fn chrome<T>(path: &Path, f: impl FnOnce() -> T) -> Result<T> {
+    let file = File::create(path).with_context(|| format!("create {}", path.display()))?;
+    let (layer, guard) = ChromeLayerBuilder::new()
+        .writer(BufWriter::new(file))
+        .include_args(true)
+        .build();
+    let output = tracing::subscriber::with_default(Registry::default().with(layer), f);
+    // Dropping the guard finishes writing the trace
+    drop(guard);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::days::{self, Part};
+
+    const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+    /// Lines of the tree without the timings
+    fn lines(tree: &Tree) -> Vec<String> {
+        tree.to_string()
+            .lines()
+            .map(|line| match line.rsplit_once(' ') {
+                Some((label, time)) if time.ends_with('s') => label.to_string(),
+                _ => line.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tree() -> Result<()> {
+        let (answer, tree) = tree(|| days::get(9)?.process(Part::Two, INPUT));
+        assert_eq!(answer?, 2.into());
+        assert_eq!(
+            lines(&tree),
+            [
+                "day{day=9}",
+                "└─ part{part=2}",
+                "   ├─ parse",
+                "   └─ solve"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tree_events() -> Result<()> {
+        let input = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+        let (answer, tree) = tree(|| days::get(10)?.timed(input, &Part::ALL));
+        assert_eq!(answer?.parts.len(), 2);
+        assert_eq!(
+            lines(&tree),
+            [
+                "day{day=10}",
+                "├─ parse",
+                "├─ part{part=1}",
+                "│  └─ solve",
+                "│     └─ loop length=16",
+                "└─ part{part=2}",
+                "   └─ solve",
+                "      └─ loop length=16",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_chrome() -> Result<()> {
+        let path = env::temp_dir().join("aoc-test-trace.json");
+        let answer = chrome(&path, || days::get(9)?.process(Part::One, INPUT))?;
+        assert_eq!(answer?, 114.into());
+        let trace: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let names: Vec<_> = trace
+            .as_array()
+            .expect("an array of events")
+            .iter()
+            .filter_map(|event| event["name"].as_str())
+            .collect();
+        for name in ["day", "part", "parse", "solve"] {
+            assert!(names.contains(&name), "no {name} in {names:?}");
+        }
+        fs::remove_file(path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    panic,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use aoc_common::input::Source;
+use ratatui::{
+    crossterm::event::{self, Event, KeyEventKind},
+    DefaultTerminal,
+};
+
+use crate::days::{self, Part, Timings};
+
+pub use app::App;
+
+mod app;
+mod ui;
+
+/// How often the screen is redrawn while waiting for keys, to update running timings
+const TICK: Duration = Duration::from_millis(100);
+
+/// Name of the threads running the solvers, whose panics are reported as failures
+const SOLVER: &str = "solver";
+
+/// Run a day in the background, sending back where its input came from and its timings
+fn spawn(day: u8, tx: mpsc::Sender<(u8, String, Result<Timings>)>) -> Result<()> {
+    thread::Builder::new()
+        .name(SOLVER.to_string())
+        .spawn(move || {
+            let source = Source::resolve(day, None);
+            let timings = days::catch_panic(|| {
+                days::get(day).and_then(|solver| solver.timed(&source.read()?, &Part::ALL))
+            });
+            // The dashboard may have quit in the meantime
+            let _ = tx.send((day, source.to_string(), timings));
+        })?;
+    Ok(())
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, mut app: App) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, &app, Instant::now()))?;
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    if let Some(day) = app.on_key(key.code) {
+                        app.start(day, Instant::now());
+                        spawn(day, tx.clone())?;
+                    }
+                }
+            }
+        }
+        for (day, name, timings) in rx.try_iter() {
+            app.finish(day, &name, timings);
+        }
+    }
+    Ok(())
+}
+
+/// Show the calendar dashboard until the user quits
+pub fn run(app: App) -> Result<()> {
+    let mut terminal = ratatui::init();
+    // Solver panics would be printed over the raw mode screen, only report the others
+    let hook = Arc::new(panic::take_hook());
+    let previous = hook.clone();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(SOLVER) {
+            hook(info)
+        }
+    }));
+    let result = event_loop(&mut terminal, app);
+    ratatui::restore();
+    panic::set_hook(Box::new(move |info| previous(info)));
+    result
+}
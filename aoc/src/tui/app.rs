@@ -0,0 +1,280 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use aoc_common::Answer;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    days::{self, Part, Timings},
+    report,
+};
+
+/// Number of days in the calendar
+pub const CALENDAR_DAYS: u8 = 25;
+
+/// Days per row of the calendar
+pub const WEEK: usize = 5;
+
+/// State of a part in the calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No solver for this day yet
+    Unavailable,
+    MissingInput,
+    /// Not run yet
+    Pending,
+    Running,
+    /// Matches the known answer
+    Solved,
+    /// Ran but no known answer to compare with
+    Unverified,
+    /// Failed or gave a wrong answer
+    Failing,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartView {
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub solve: Option<Duration>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayView {
+    pub day: u8,
+    pub parts: [PartView; 2],
+    pub parse: Option<Duration>,
+    /// When the running solver was started
+    pub started: Option<Instant>,
+}
+
+impl DayView {
+    fn new(day: u8, status: Status, expected: &HashMap<(u8, Part), Answer>) -> Self {
+        let part = |part| PartView {
+            status,
+            answer: None,
+            expected: expected.get(&(day, part)).cloned(),
+            solve: None,
+            error: None,
+        };
+        DayView {
+            day,
+            parts: [part(Part::One), part(Part::Two)],
+            parse: None,
+            started: None,
+        }
+    }
+
+    pub fn part(&self, part: Part) -> &PartView {
+        &self.parts[u8::from(part) as usize - 1]
+    }
+
+    fn can_run(&self) -> bool {
+        self.parts
+            .iter()
+            .all(|part| !matches!(part.status, Status::Unavailable | Status::Running))
+    }
+}
+
+/// State of the dashboard
+#[derive(Debug)]
+pub struct App {
+    pub days: Vec<DayView>,
+    /// Index of the selected day
+    pub selected: usize,
+    pub quit: bool,
+}
+
+impl App {
+    /// Build the calendar from the known answers and whether each day has an input
+    pub fn new(expected: &HashMap<(u8, Part), Answer>, has_input: impl Fn(u8) -> bool) -> Self {
+        let days = (1..=CALENDAR_DAYS)
+            .map(|day| {
+                let status = if days::get(day).is_err() {
+                    Status::Unavailable
+                } else if !has_input(day) {
+                    Status::MissingInput
+                } else {
+                    Status::Pending
+                };
+                DayView::new(day, status, expected)
+            })
+            .collect();
+        App {
+            days,
+            selected: 0,
+            quit: false,
+        }
+    }
+
+    pub fn selected(&self) -> &DayView {
+        &self.days[self.selected]
+    }
+
+    /// Handle a key press, returning the day to run if any
+    pub fn on_key(&mut self, key: KeyCode) -> Option<u8> {
+        let last = self.days.len() - 1;
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Left | KeyCode::Char('h') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(WEEK),
+            KeyCode::Down | KeyCode::Char('j') if self.selected + WEEK <= last => {
+                self.selected += WEEK
+            }
+            KeyCode::Enter | KeyCode::Char('r') if self.selected().can_run() => {
+                return Some(self.selected().day)
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Mark a day as running
+    pub fn start(&mut self, day: u8, now: Instant) {
+        let view = &mut self.days[day as usize - 1];
+        view.started = Some(now);
+        view.parse = None;
+        for part in &mut view.parts {
+            part.status = Status::Running;
+            part.answer = None;
+            part.solve = None;
+            part.error = None;
+        }
+    }
+
+    /// Record the outcome of a run, `name` being where its input was read from
+    pub fn finish(&mut self, day: u8, name: &str, timings: Result<Timings>) {
+        let view = &mut self.days[day as usize - 1];
+        view.started = None;
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(e) => {
+                let error = report::render(&e, name);
+                for part in &mut view.parts {
+                    part.status = Status::Failing;
+                    part.error = Some(error.clone());
+                }
+                return;
+            }
+        };
+        view.parse = Some(timings.parse);
+        for timing in timings.parts {
+            let part = &mut view.parts[u8::from(timing.part) as usize - 1];
+            part.solve = Some(timing.solve);
+            match timing.answer {
+                Ok(answer) => {
+                    part.status = match &part.expected {
+                        Some(expected) if *expected == answer => Status::Solved,
+                        Some(expected) => {
+                            part.error = Some(format!("expected {expected}"));
+                            Status::Failing
+                        }
+                        None => Status::Unverified,
+                    };
+                    part.answer = Some(answer);
+                }
+                Err(e) => {
+                    part.status = Status::Failing;
+                    part.error = Some(report::render(&e, name));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+    fn app() -> App {
+        let expected = HashMap::from([((9, Part::One), 114.into()), ((9, Part::Two), 3.into())]);
+        App::new(&expected, |day| day != 2)
+    }
+
+    #[test]
+    fn test_statuses() {
+        let app = app();
+        assert_eq!(app.days.len(), 25);
+        assert_eq!(app.days[0].part(Part::One).status, Status::Pending);
+        assert_eq!(app.days[1].part(Part::Two).status, Status::MissingInput);
+        assert_eq!(app.days[24].part(Part::One).status, Status::Unavailable);
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut app = app();
+        app.on_key(KeyCode::Left);
+        assert_eq!(app.selected, 0);
+        app.on_key(KeyCode::Down);
+        app.on_key(KeyCode::Char('l'));
+        assert_eq!(app.selected, 6);
+        app.on_key(KeyCode::Up);
+        assert_eq!(app.selected, 1);
+        app.selected = 22;
+        app.on_key(KeyCode::Down);
+        assert_eq!(app.selected, 22);
+        app.on_key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn test_run_selected() {
+        let mut app = app();
+        app.selected = 8;
+        assert_eq!(app.on_key(KeyCode::Enter), Some(9));
+        app.start(9, Instant::now());
+        assert_eq!(app.on_key(KeyCode::Enter), None);
+        app.selected = 24;
+        assert_eq!(app.on_key(KeyCode::Enter), None);
+    }
+
+    #[test]
+    fn test_finish() -> Result<()> {
+        let mut app = app();
+        app.start(9, Instant::now());
+        app.finish(9, "day-09.txt", days::get(9)?.timed(INPUT, &Part::ALL));
+        let day = &app.days[8];
+        assert!(day.parse.is_some());
+        assert_eq!(day.part(Part::One).status, Status::Solved);
+        assert_eq!(day.part(Part::Two).status, Status::Failing);
+        assert_eq!(day.part(Part::Two).error.as_deref(), Some("expected 3"));
+
+        app.start(2, Instant::now());
+        app.finish(
+            2,
+            "day-02.txt",
+            days::get(2)?.timed("Game 1 3 red", &Part::ALL),
+        );
+        let day = &app.days[1];
+        assert_eq!(day.part(Part::One).status, Status::Failing);
+        assert!(day
+            .part(Part::One)
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("day-02.txt:1:7"));
+
+        app.start(8, Instant::now());
+        let input = "L\n\nAAA = (BBB, BBB)\n";
+        let timings = days::catch_panic(|| days::get(8)?.timed(input, &Part::ALL));
+        app.finish(8, "day-08.txt", timings);
+        let day = &app.days[7];
+        assert_eq!(day.started, None);
+        assert_eq!(day.part(Part::Two).status, Status::Failing);
+        assert!(day
+            .part(Part::Two)
+            .error
+            .as_ref()
+            .unwrap()
+            .starts_with("solver panicked: "));
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use std::time::Instant;
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+    Frame,
+};
+
+use super::app::{App, DayView, PartView, Status, WEEK};
+use crate::{days::Part, summary::format_duration};
+
+/// Width of a calendar cell, borders excluded
+const CELL_WIDTH: u16 = 10;
+
+fn symbol(status: Status) -> Span<'static> {
+    match status {
+        Status::Unavailable => " ".into(),
+        Status::MissingInput => "·".dark_gray(),
+        Status::Pending => "○".white(),
+        Status::Running => "…".blue(),
+        Status::Solved => "★".yellow(),
+        Status::Unverified => "☆".cyan(),
+        Status::Failing => "✗".red(),
+    }
+}
+
+fn label(status: Status) -> &'static str {
+    match status {
+        Status::Unavailable => "not solved yet",
+        Status::MissingInput => "missing input",
+        Status::Pending => "not run",
+        Status::Running => "running",
+        Status::Solved => "solved",
+        Status::Unverified => "no known answer",
+        Status::Failing => "failing",
+    }
+}
+
+fn cell(view: &DayView, selected: bool) -> Vec<Span<'static>> {
+    let mut day = Span::raw(format!(" {:>2} ", view.day));
+    if view.parts[0].status == Status::Unavailable {
+        day = day.dark_gray();
+    }
+    let mut spans = vec![
+        day,
+        symbol(view.parts[0].status),
+        " ".into(),
+        symbol(view.parts[1].status),
+        "   ".into(),
+    ];
+    if selected {
+        for span in &mut spans {
+            span.style = span.style.add_modifier(Modifier::REVERSED);
+        }
+    }
+    spans
+}
+
+fn calendar(app: &App) -> Paragraph<'static> {
+    let lines: Vec<Line> = app
+        .days
+        .chunks(WEEK)
+        .enumerate()
+        .flat_map(|(week, days)| {
+            let spans = days
+                .iter()
+                .enumerate()
+                .flat_map(|(i, view)| cell(view, week * WEEK + i == app.selected))
+                .collect::<Vec<_>>();
+            [Line::from(spans), Line::default()]
+        })
+        .collect();
+    Paragraph::new(lines).block(Block::bordered().title(" Advent of Code 2023 "))
+}
+
+fn part_lines(part: Part, view: &PartView, running_for: Option<String>) -> Vec<Line<'static>> {
+    let mut header = vec![
+        format!("Part {part}  ").bold(),
+        symbol(view.status),
+        format!(" {}", label(view.status)).into(),
+    ];
+    if let Some(elapsed) = running_for {
+        header.push(format!(" for {elapsed}").into());
+    }
+    let mut lines = vec![Line::from(header)];
+    if let Some(answer) = &view.answer {
+        lines.push(Line::from(vec![
+            "  answer ".dark_gray(),
+            answer.to_string().into(),
+        ]));
+    }
+    if let Some(solve) = view.solve {
+        lines.push(Line::from(vec![
+            "  solve  ".dark_gray(),
+            format_duration(solve).into(),
+        ]));
+    }
+    if let Some(error) = &view.error {
+        lines.extend(
+            error
+                .lines()
+                .map(|line| Line::styled(format!("  {line}"), Style::new().fg(Color::Red))),
+        );
+    }
+    lines.push(Line::default());
+    lines
+}
+
+fn details(view: &DayView, now: Instant) -> Paragraph<'static> {
+    let running_for = view
+        .started
+        .map(|started| format_duration(now.duration_since(started)));
+    let mut lines = vec![];
+    if let Some(parse) = view.parse {
+        lines.push(Line::from(vec![
+            "parse    ".dark_gray(),
+            format_duration(parse).into(),
+        ]));
+        lines.push(Line::default());
+    }
+    for part in Part::ALL {
+        lines.extend(part_lines(part, view.part(part), running_for.clone()));
+    }
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::bordered().title(format!(" Day {} ", view.day)))
+}
+
+fn help() -> Paragraph<'static> {
+    Paragraph::new(Line::from(vec![
+        " ←↑↓→ ".bold(),
+        "select  ".into(),
+        "enter ".bold(),
+        "run  ".into(),
+        "q ".bold(),
+        "quit   ".into(),
+        symbol(Status::Solved),
+        " solved ".into(),
+        symbol(Status::Unverified),
+        " unverified ".into(),
+        symbol(Status::Failing),
+        " failing ".into(),
+        symbol(Status::Pending),
+        " not run ".into(),
+        symbol(Status::MissingInput),
+        " missing input".into(),
+    ]))
+}
+
+/// Draw the calendar next to the details of the selected day
+pub fn draw(frame: &mut Frame, app: &App, now: Instant) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [left, right] = Layout::horizontal([
+        Constraint::Length(CELL_WIDTH * WEEK as u16 + 2),
+        Constraint::Min(0),
+    ])
+    .areas(main);
+    frame.render_widget(calendar(app), left);
+    frame.render_widget(details(app.selected(), now), right);
+    frame.render_widget(help(), footer);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn text(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_draw() -> anyhow::Result<()> {
+        let mut app = App::new(&HashMap::new(), |day| day != 2);
+        app.selected = 8;
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        app.finish(
+            9,
+            "day-09.txt",
+            crate::days::get(9)?.timed(input, &Part::ALL),
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 14))?;
+        terminal.draw(|frame| draw(frame, &app, Instant::now()))?;
+        let text = text(&terminal);
+        assert!(text.contains("  1 ○ ○"));
+        assert!(text.contains("  2 · ·"));
+        assert!(text.contains("  9 ☆ ☆"));
+        assert!(text.contains(" Day 9 "));
+        assert!(text.contains("answer 114"));
+        Ok(())
+    }
+}
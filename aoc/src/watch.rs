@@ -0,0 +1,261 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::input;
+use notify::{Event, RecursiveMode, Watcher};
+
+use crate::{
+    days::Part,
+    json::{Run, RunError},
+};
+
+/// Time to wait for a burst of changes, such as an editor saving, to settle
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Answers of a run, by part
+pub type Answers = BTreeMap<Part, Result<String, String>>;
+
+/// Return the package name of a crate
+pub fn package_name(dir: &Path) -> Result<String> {
+    let path = dir.join("Cargo.toml");
+    let manifest: toml::Table = fs::read_to_string(&path)
+        .with_context(|| format!("read {}", path.display()))?
+        .parse()
+        .with_context(|| format!("parse {}", path.display()))?;
+    manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(String::from)
+        .ok_or(anyhow!("missing package name in {}", path.display()))
+}
+
+/// Return the sources and the input of a day, which trigger a new run when changed
+pub fn watched_paths(root: &Path, day: u8) -> [PathBuf; 2] {
+    [
+        root.join(format!("day-{day:02}")).join("src"),
+        input::day_path(&input::input_dir_in(root), day),
+    ]
+}
+
+/// Whether an event changed one of the watched paths, rather than a sibling input
+/// or a mere read
+fn triggers(event: &Event, paths: &[PathBuf]) -> bool {
+    !event.kind.is_access()
+        && event
+            .paths
+            .iter()
+            .any(|changed| paths.iter().any(|path| changed.starts_with(path)))
+}
+
+fn error_text(error: &RunError) -> String {
+    match (error.line, error.column) {
+        (Some(line), Some(column)) => format!("{} at line {line}, column {column}", error.message),
+        _ => error.message.clone(),
+    }
+}
+
+/// Keep the answer or error of each part of the runs
+pub fn answers(runs: &[Run]) -> Answers {
+    runs.iter()
+        .map(|run| {
+            let answer = match (&run.answer, &run.error) {
+                (Some(answer), _) => Ok(answer.clone()),
+                (None, Some(error)) => Err(error_text(error)),
+                (None, None) => Err("no answer".to_string()),
+            };
+            (run.part, answer)
+        })
+        .collect()
+}
+
+fn describe(answer: Option<&Result<String, String>>) -> String {
+    match answer {
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(error)) => format!("error: {error}"),
+        None => "nothing".to_string(),
+    }
+}
+
+/// Describe the parts whose answer changed since the previous run
+pub fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    Part::ALL
+        .iter()
+        .filter(|part| previous.get(part) != current.get(part))
+        .map(|part| {
+            format!(
+                "part {part}: {} -> {}",
+                describe(previous.get(part)),
+                describe(current.get(part))
+            )
+        })
+        .collect()
+}
+
+/// Run the tests of the day crate, returning whether they passed
+fn test(root: &Path, package: &str) -> Result<bool> {
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--package", package])
+        .current_dir(root)
+        .status()
+        .context("run cargo test")?;
+    Ok(status.success())
+}
+
+/// Rebuild the runner and run both parts of the day
+fn run(root: &Path, day: u8) -> Result<Vec<Run>> {
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--bin", "aoc", "--"])
+        .args(["run", "--day", &day.to_string(), "--format", "json"])
+        .current_dir(root)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("run cargo run")?;
+    if output.stdout.is_empty() {
+        bail!("the runner did not build");
+    }
+    serde_json::from_slice(&output.stdout).context("parse the runner output")
+}
+
+/// Test and run a day, printing its answers and what changed since the previous run
+fn run_once(root: &Path, day: u8, package: &str, previous: &mut Option<Answers>) -> Result<()> {
+    let passed = test(root, package)?;
+    println!("tests {}", if passed { "passed" } else { "failed" });
+
+    let current = answers(&run(root, day)?);
+    for (part, answer) in &current {
+        println!("Day {day:02} part {part}: {}", describe(Some(answer)));
+    }
+    if let Some(previous) = previous {
+        match diff(previous, &current).as_slice() {
+            [] => println!("answers unchanged"),
+            changes => changes
+                .iter()
+                .for_each(|change| println!("changed {change}")),
+        }
+    }
+    *previous = Some(current);
+    Ok(())
+}
+
+/// Re-run a day every time its sources or its input change, until interrupted
+pub fn watch(root: &Path, day: u8) -> Result<()> {
+    let package = package_name(&root.join(format!("day-{day:02}")))?;
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    let paths = watched_paths(root, day);
+    let [src, input] = &paths;
+    watcher.watch(src, RecursiveMode::Recursive)?;
+    // Watch the inputs directory rather than the file, which may not exist yet and
+    // which editors replace when saving
+    let inputs = input
+        .parent()
+        .expect("an input is inside the inputs directory");
+    fs::create_dir_all(inputs).with_context(|| format!("create {}", inputs.display()))?;
+    watcher.watch(inputs, RecursiveMode::NonRecursive)?;
+
+    let mut previous = None;
+    loop {
+        if let Err(e) = run_once(root, day, &package, &mut previous) {
+            eprintln!("{e:#}");
+        }
+        println!("watching day {day:02} for changes");
+
+        while !triggers(&rx.recv()?.context("watch for changes")?, &paths) {}
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notify::{
+        event::{AccessKind, CreateKind, ModifyKind},
+        EventKind,
+    };
+
+    use super::*;
+
+    fn run(part: Part, answer: Option<&str>, error: Option<RunError>) -> Run {
+        Run {
+            day: 5,
+            part,
+            answer: answer.map(String::from),
+            parse_ns: None,
+            solve_ns: None,
+            parse_allocations: None,
+            solve_allocations: None,
+            input_hash: None,
+            error,
+        }
+    }
+
+    #[test]
+    fn test_package_name() -> Result<()> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        assert_eq!(package_name(&root.join("day-01"))?, "day_1");
+        assert_eq!(package_name(&root.join("day-05"))?, "day-05");
+        Ok(())
+    }
+
+    #[test]
+    fn test_watched_paths() {
+        let paths = watched_paths(Path::new("/aoc"), 5);
+        assert_eq!(paths[0], Path::new("/aoc/day-05/src"));
+        assert!(paths[1].ends_with("day-05.txt"));
+    }
+
+    #[test]
+    fn test_triggers() {
+        let paths = [
+            PathBuf::from("/aoc/day-05/src"),
+            PathBuf::from("/aoc/inputs/day-05.txt"),
+        ];
+        let event = |kind, path: &str| Event::new(kind).add_path(PathBuf::from(path));
+        let modify = EventKind::Modify(ModifyKind::Any);
+        assert!(triggers(&event(modify, "/aoc/day-05/src/part1.rs"), &paths));
+        assert!(triggers(&event(modify, "/aoc/inputs/day-05.txt"), &paths));
+        assert!(triggers(
+            &event(
+                EventKind::Create(CreateKind::File),
+                "/aoc/inputs/day-05.txt"
+            ),
+            &paths
+        ));
+        assert!(!triggers(&event(modify, "/aoc/inputs/day-06.txt"), &paths));
+        assert!(!triggers(&event(modify, "/aoc/day-05/Cargo.toml"), &paths));
+        assert!(!triggers(
+            &event(EventKind::Access(AccessKind::Any), "/aoc/day-05/src/lib.rs"),
+            &paths
+        ));
+    }
+
+    #[test]
+    fn test_diff() {
+        let error = RunError {
+            message: "expected a number".into(),
+            line: Some(2),
+            column: Some(3),
+        };
+        let previous = answers(&[
+            run(Part::One, Some("35"), None),
+            run(Part::Two, Some("46"), None),
+        ]);
+        let current = answers(&[
+            run(Part::One, Some("35"), None),
+            run(Part::Two, None, Some(error)),
+        ]);
+        assert!(diff(&previous, &previous).is_empty());
+        assert_eq!(
+            diff(&previous, &current),
+            ["part 2: 46 -> error: expected a number at line 2, column 3"]
+        );
+    }
+}
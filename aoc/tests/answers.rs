@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use anyhow::Result;
+use aoc::{
+    answers::{self, Expected},
+    days,
+};
+use aoc_common::{
+    input::{self, Source},
+    Answer,
+};
+
+fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+fn answers() -> Result<Vec<Expected>> {
+    answers::load(&workspace_root().join("answers.toml"))
+}
+
+#[test]
+fn test_answers_are_registered() -> Result<()> {
+    for answer in answers()? {
+        days::get(answer.day)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_known_answers() -> Result<()> {
+    let dir = input::input_dir_in(workspace_root());
+    let mut mismatches = vec![];
+    for answer in answers()? {
+        let source = Source::Day(answer.day, input::day_path(&dir, answer.day));
+        if source.is_missing() {
+            eprintln!(
+                "skipping day {} part {}: missing {source}",
+                answer.day, answer.part
+            );
+            continue;
+        }
+        let expected: Answer = answer.expected.parse()?;
+        let output = days::get(answer.day)?.process(answer.part, &source.read()?);
+        match output {
+            Ok(output) if output == expected => {}
+            Ok(output) => mismatches.push(format!(
+                "day {} part {}: expected {}, got {output}",
+                answer.day, answer.part, answer.expected
+            )),
+            Err(e) => mismatches.push(format!(
+                "day {} part {}: expected {}, failed with {e:#}",
+                answer.day, answer.part, answer.expected
+            )),
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    Ok(())
+}
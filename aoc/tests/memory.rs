@@ -0,0 +1,35 @@
+use anyhow::Result;
+use aoc::{
+    days::{self, Part},
+    memory::{self, Counting},
+};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+// Kept to a single test, as the counters are shared by every thread of the binary
+#[test]
+fn test_measure() -> Result<()> {
+    let (buffer, allocations) = memory::measure(|| vec![0u8; 1 << 20]);
+    let allocations = allocations.expect("the allocator to be installed");
+    assert!(allocations.count >= 1);
+    assert!(allocations.bytes >= 1 << 20);
+    assert!(allocations.peak >= 1 << 20);
+    assert!(allocations.retained >= 1 << 20);
+    let (_, allocations) = memory::measure(|| drop(buffer));
+    assert_eq!(allocations.map(|a| a.retained), Some(0));
+
+    let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+    let timings = days::get(9)?.timed(input, &Part::ALL)?;
+    let parse = timings.parse_allocations.expect("the parse to be counted");
+    // Three sequences of six values
+    assert!(parse.retained >= 3 * 6 * 8);
+    for part in timings.parts {
+        let solve = part.allocations.expect("the solve to be counted");
+        assert!(solve.count > 0, "{solve:?}");
+        let total = parse.then(solve);
+        assert_eq!(total.count, parse.count + solve.count);
+        assert!(total.peak >= parse.peak);
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::{cmp::Ordering, convert::Infallible, fmt, str::FromStr};
+
+/// Answer of a puzzle part
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// Integer too large for 64 bits
+    Big(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// Return the numeric value, `None` for text answers
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n as i128),
+            Answer::Unsigned(n) => Some(*n as i128),
+            Answer::Big(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parse the smallest fitting integer, falling back to text
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(if let Ok(n) = s.parse() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Signed(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Big(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+/// Numbers compare by value whatever their width, text compares as is
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => matches!((self.as_i128(), other.as_i128()), (Some(a), Some(b)) if a == b),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Only numbers are ordered
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.as_i128(), other.as_i128()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            (None, None) if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            fn from(value: $ty) -> Self {
+                Answer::$variant(value.into())
+            }
+        })*
+    };
+}
+
+impl_from!(Signed: i32, i64);
+impl_from!(Unsigned: u32, u64);
+impl_from!(Big: i128);
+impl_from!(Text: String, &str);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("42".parse(), Ok(Answer::Unsigned(42))));
+        assert!(matches!("-42".parse(), Ok(Answer::Signed(-42))));
+        assert!(matches!(
+            "100000000000000000000".parse(),
+            Ok(Answer::Big(100000000000000000000))
+        ));
+        assert!(matches!("abc".parse(), Ok(Answer::Text(t)) if t == "abc"));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for answer in ["42", "-42", "-100000000000000000000", "EZ"] {
+            assert_eq!(answer.parse::<Answer>().unwrap().to_string(), answer);
+        }
+    }
+
+    #[test]
+    fn test_eq_across_widths() {
+        assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+        assert_eq!(Answer::Big(5), 5.into());
+        assert_ne!(Answer::Signed(-5), Answer::Unsigned(5));
+        assert_ne!(Answer::Text("5".to_string()), Answer::Unsigned(5));
+        assert_eq!(Answer::from("abc"), Answer::from("abc"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Answer::Signed(-1) < Answer::Unsigned(0));
+        assert!(Answer::Big(1 << 70) > Answer::Unsigned(u64::MAX));
+        assert_eq!(Answer::from("a").partial_cmp(&Answer::Unsigned(1)), None);
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fs, path::Path};
+
+use criterion::{BenchmarkId, Criterion};
+
+use crate::{input, Solver};
+
+/// Return the real input of a day, when it is present
+fn real_input(day: u8) -> Option<String> {
+    // Benchmarks run from the day crate, so default to the workspace inputs directory
+    let dir = input::input_dir_in(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".."));
+    fs::read_to_string(input::day_path(&dir, day)).ok()
+}
+
+/// Benchmark the parsing and both parts of a solver.
+///
+/// Each part runs against its own example, then everything runs against the
+/// real input of the day if it is present.
+pub fn bench_solver<S: Solver>(c: &mut Criterion, example1: &str, example2: &str) {
+    let mut group = c.benchmark_group(format!("day-{:02}", S::DAY));
+    bench_input::<S>(&mut group, "example", example1, example2);
+    if let Some(input) = real_input(S::DAY) {
+        group.sample_size(10);
+        bench_input::<S>(&mut group, "input", &input, &input);
+    }
+    group.finish();
+}
+
+fn bench_input<S: Solver>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    name: &str,
+    input1: &str,
+    input2: &str,
+) {
+    group.bench_function(BenchmarkId::new("parse", name), |b| {
+        b.iter(|| S::parse(input1).expect("to parse"))
+    });
+    let parsed = S::parse(input1).expect("to parse");
+    group.bench_function(BenchmarkId::new("part1", name), |b| {
+        b.iter(|| S::part1(&parsed).expect("to solve part 1"))
+    });
+    let parsed = S::parse(input2).expect("to parse");
+    group.bench_function(BenchmarkId::new("part2", name), |b| {
+        b.iter(|| S::part2(&parsed).expect("to solve part 2"))
+    });
+}
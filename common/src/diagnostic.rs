@@ -0,0 +1,127 @@
+use std::{error, fmt, ops::Range};
+
+/// Error pointing at a span of the puzzle input, with an optional hint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// What went wrong
+    pub message: String,
+    /// Input the span points into
+    pub source: String,
+    /// Byte span of the offending input
+    pub span: Range<usize>,
+    /// How to fix the input
+    pub help: Option<String>,
+}
+
+/// Return the line and column, both starting at 1, of a byte offset
+pub fn location(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl Diagnostic {
+    /// Point at a span of the input
+    pub fn new(source: &str, span: Range<usize>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            message: message.into(),
+            source: source.to_string(),
+            span,
+            help: None,
+        }
+    }
+
+    /// Attach a hint on how to fix the input
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Return the line and column, both starting at 1, of the start of the span
+    pub fn location(&self) -> (usize, usize) {
+        location(&self.source, self.span.start)
+    }
+
+    /// Render the message along with the offending line and a caret under the span
+    pub fn render(&self, name: &str) -> String {
+        let (line, column) = self.location();
+        let line_start = self.source[..self.span.start]
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+        let text = self.source[line_start..]
+            .split(['\r', '\n'])
+            .next()
+            .unwrap_or_default();
+        let end = self.span.end.min(line_start + text.len());
+        let width = self.source[self.span.start.min(end)..end]
+            .chars()
+            .count()
+            .max(1);
+
+        let gutter = " ".repeat(line.to_string().len());
+        let mut out = format!("error: {}\n", self.message);
+        out += &format!("{gutter}--> {name}:{line}:{column}\n");
+        out += &format!("{gutter} |\n");
+        out += &format!("{line} | {text}\n");
+        out += &format!("{gutter} | {}{}", " ".repeat(column - 1), "^".repeat(width));
+        if let Some(help) = &self.help {
+            out += &format!("\n{gutter} = help: {help}");
+        }
+        out
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (line, column) = self.location();
+        write!(f, "{} at line {line}, column {column}", self.message)
+    }
+}
+
+impl error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let source = "ab\ncdé\nf";
+        assert_eq!(location(source, 0), (1, 1));
+        assert_eq!(location(source, 4), (2, 2));
+        assert_eq!(location(source, 8), (3, 1));
+    }
+
+    #[test]
+    fn test_render() {
+        let diagnostic =
+            Diagnostic::new("Card 1: 41 | 41\nCard 2: 83 / x\n", 27..28, "expected `|`")
+                .with_help("cards look like `Card 1: 41 48 | 83 86`");
+        assert_eq!(
+            diagnostic.render("day-04.txt"),
+            "error: expected `|`
+ --> day-04.txt:2:12
+  |
+2 | Card 2: 83 / x
+  |            ^
+  = help: cards look like `Card 1: 41 48 | 83 86`"
+        );
+        assert_eq!(diagnostic.to_string(), "expected `|` at line 2, column 12");
+    }
+
+    #[test]
+    fn test_render_end_of_input() {
+        let diagnostic = Diagnostic::new("12\n3", 4..4, "expected a digit");
+        assert_eq!(
+            diagnostic.render("<stdin>"),
+            "error: expected a digit
+ --> <stdin>:2:2
+  |
+2 | 3
+  |  ^"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+/// Environment variable overriding the inputs directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Inputs directory used when `AOC_INPUT_DIR` is not set
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Explicit file given by the user
+    File(PathBuf),
+    /// Standard input, selected with `-`
+    Stdin,
+    /// Conventional `day-NN.txt` file of the inputs directory
+    Day(u8, PathBuf),
+}
+
+impl Source {
+    /// Resolve the input of a day from an optional user given path
+    pub fn resolve(day: u8, path: Option<&Path>) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_path_buf()),
+            None => Source::Day(day, day_path(&input_dir(), day)),
+        }
+    }
+
+    /// Whether the input file does not exist
+    pub fn is_missing(&self) -> bool {
+        match self {
+            Source::Stdin => false,
+            Source::File(path) | Source::Day(_, path) => !path.exists(),
+        }
+    }
+
+    /// Read the whole input
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("read input from stdin")?;
+                Ok(input)
+            }
+            Source::File(path) => {
+                fs::read_to_string(path).with_context(|| format!("read input {}", path.display()))
+            }
+            Source::Day(day, path) if !path.exists() => bail!(
+                "missing input for day {day}: {} does not exist, \
+                 save the puzzle input there or set {INPUT_DIR_VAR}",
+                path.display()
+            ),
+            Source::Day(_, path) => {
+                fs::read_to_string(path).with_context(|| format!("read input {}", path.display()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) | Source::Day(_, path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Return the inputs directory, from `AOC_INPUT_DIR` or the default one
+pub fn input_dir() -> PathBuf {
+    input_dir_in(Path::new(""))
+}
+
+/// Return the inputs directory, resolving the default one inside `root`
+pub fn input_dir_in(root: &Path) -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join(DEFAULT_INPUT_DIR))
+}
+
+/// Return the conventional input file of a day inside the given directory
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day-{day:02}.txt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_path() {
+        assert_eq!(
+            day_path(Path::new("inputs"), 5),
+            PathBuf::from("inputs/day-05.txt")
+        );
+        assert_eq!(
+            day_path(Path::new("/tmp"), 11),
+            PathBuf::from("/tmp/day-11.txt")
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Source::resolve(1, Some(Path::new("-"))), Source::Stdin);
+        assert_eq!(
+            Source::resolve(1, Some(Path::new("input.txt"))),
+            Source::File(PathBuf::from("input.txt"))
+        );
+        assert!(matches!(Source::resolve(3, None), Source::Day(3, _)));
+    }
+
+    #[test]
+    fn test_is_missing() {
+        assert!(!Source::Stdin.is_missing());
+        assert!(Source::Day(7, PathBuf::from("does/not/exist/day-07.txt")).is_missing());
+    }
+
+    #[test]
+    fn test_read_missing_day() {
+        let source = Source::Day(7, PathBuf::from("does/not/exist/day-07.txt"));
+        let err = source.read().unwrap_err().to_string();
+        assert!(err.contains("missing input for day 7"));
+        assert!(err.contains("does/not/exist/day-07.txt"));
+    }
+
+    #[test]
+    fn test_read_file() -> Result<()> {
+        let path = env::temp_dir().join("aoc-common-test-read-file.txt");
+        fs::write(&path, "1abc2")?;
+        assert_eq!(Source::File(path.clone()).read()?, "1abc2");
+        fs::remove_file(path)?;
+        Ok(())
+    }
+}
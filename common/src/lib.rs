@@ -0,0 +1,10 @@
+mod answer;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod diagnostic;
+pub mod input;
+pub mod parse;
+mod solver;
+
+pub use answer::Answer;
+pub use solver::Solver;
@@ -0,0 +1,236 @@
+use std::{error, fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0, space1},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{pair, terminated},
+    Finish, Parser,
+};
+
+use crate::diagnostic::{self, Diagnostic};
+
+/// Result of the parsers, keeping track of the context of a failure
+pub type IResult<'a, O> = nom::IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Malformed input along with where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What was expected
+    pub message: String,
+    /// Byte offset in the input
+    pub offset: usize,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+}
+
+impl ParseError {
+    /// Build an error at the given byte offset of the input
+    pub fn new(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let (line, column) = diagnostic::location(input, offset);
+        ParseError {
+            message: message.into(),
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Turn into a diagnostic pointing at the offending character of the input
+    pub fn into_diagnostic(self, input: &str) -> Diagnostic {
+        let len = input[self.offset..]
+            .chars()
+            .next()
+            .filter(|c| *c != '\n')
+            .map_or(0, char::len_utf8);
+        Diagnostic::new(input, self.offset..self.offset + len, self.message)
+    }
+
+    fn from_verbose(input: &str, error: VerboseError<&str>) -> Self {
+        let Some((rest, kind)) = error.errors.first() else {
+            return ParseError::new(input, 0, "invalid input");
+        };
+        let mut contexts = error.errors[1..].iter().filter_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(context),
+            _ => None,
+        });
+        let mut message = describe(kind);
+        // When every alternative failed, name the alternatives instead of the last one
+        if let Some((_, VerboseErrorKind::Nom(ErrorKind::Alt))) = error.errors.get(1) {
+            if let Some(alternatives) = contexts.next() {
+                message = format!("expected {alternatives}");
+            }
+        }
+        if let Some(context) = contexts.next() {
+            message = format!("invalid {context}: {message}");
+        }
+        ParseError::new(input, input.len() - rest.len(), message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Describe what a failed parser expected
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(literal) => format!("expected `{literal}`"),
+        VerboseErrorKind::Char(c) => format!("expected `{}`", c.escape_default()),
+        VerboseErrorKind::Nom(kind) => match kind {
+            ErrorKind::Digit => "expected a number".to_string(),
+            ErrorKind::OneOf => "unexpected character".to_string(),
+            ErrorKind::Count => "wrong number of values".to_string(),
+            ErrorKind::MapRes => "number out of range".to_string(),
+            ErrorKind::Space => "expected a space".to_string(),
+            ErrorKind::CrLf => "expected a new line".to_string(),
+            ErrorKind::AlphaNumeric => "expected a name".to_string(),
+            ErrorKind::Eof => "unexpected trailing input".to_string(),
+            ErrorKind::Tag => "unexpected input".to_string(),
+            kind => kind.description().to_lowercase(),
+        },
+    }
+}
+
+/// Run a parser over the whole input, allowing trailing whitespace
+pub fn parse<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> Result<O, ParseError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|error| ParseError::from_verbose(input, error))
+}
+
+/// Match a literal, naming it in the error when missing
+pub fn literal<'a>(expected: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        tag(expected)(input).map_err(|error: nom::Err<VerboseError<&str>>| {
+            error.map(|_| VerboseError {
+                errors: vec![(input, VerboseErrorKind::Context(expected))],
+            })
+        })
+    }
+}
+
+/// Parse an unsigned number
+pub fn number<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parse a number with an optional minus sign
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Parse space separated unsigned numbers
+pub fn numbers<T: FromStr>(input: &str) -> IResult<'_, Vec<T>> {
+    separated_list1(space1, number)(input)
+}
+
+/// Parse one item per line
+///
+/// Items should `cut` once recognized so that an error points inside the line
+/// rather than at the end of the previous one.
+pub fn lines<'a, O>(
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated_list1(line_ending, parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{branch::alt, error::context, sequence::preceded};
+
+    use super::*;
+
+    fn values(input: &str) -> IResult<'_, Vec<u32>> {
+        context("values", preceded(literal("Values: "), numbers))(input)
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse("1 22  333\n", numbers::<u32>), Ok(vec![1, 22, 333]));
+        assert_eq!(
+            parse("-4 5", separated_list1(space1, signed::<i64>)),
+            Ok(vec![-4, 5])
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse("Values: 1 2\nValues: 3\n", lines(values)),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn test_error_location() {
+        let error = parse("Values: 1 2\nValues: 3 x\n", lines(values)).unwrap_err();
+        assert_eq!((error.line, error.column, error.offset), (2, 11, 22));
+        assert_eq!(
+            error.to_string(),
+            "unexpected trailing input at line 2, column 11"
+        );
+
+        let error = parse("Values: 1\nValue: 2", lines(values)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse("Value: 2", values).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid values: expected `Values: ` at line 1, column 1"
+        );
+    }
+
+    #[test]
+    fn test_error_context() {
+        let error = parse("Values: x", values).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid values: expected a number at line 1, column 9"
+        );
+    }
+
+    #[test]
+    fn test_error_alternatives() {
+        let sign = context("a sign", alt((literal("+"), literal("-"))));
+        let error = parse("*", context("operation", sign)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid operation: expected a sign at line 1, column 1"
+        );
+    }
+
+    #[test]
+    fn test_into_diagnostic() {
+        let input = "Values: é";
+        let error = parse(input, values).unwrap_err();
+        let diagnostic = error.into_diagnostic(input);
+        assert_eq!(diagnostic.span, 8..10);
+        assert_eq!(
+            diagnostic.to_string(),
+            "invalid values: expected a number at line 1, column 9"
+        );
+    }
+
+    #[test]
+    fn test_error_out_of_range() {
+        let error = parse("300", number::<u8>).unwrap_err();
+        assert_eq!(error.to_string(), "number out of range at line 1, column 1");
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+
+use crate::Answer;
+
+/// Solution of a single day of the calendar.
+///
+/// The puzzle input is parsed once by [`Solver::parse`] and the parsed value
+/// is then shared by both parts.
+pub trait Solver {
+    /// Day of the puzzle, from 1 to 25
+    const DAY: u8;
+
+    /// Parsed puzzle input, which may borrow from the raw input
+    type Input<'a>;
+
+    /// Parse the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    /// Solve the first part from the parsed input
+    fn part1(input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// Solve the second part from the parsed input
+    fn part2(input: &Self::Input<'_>) -> Result<Answer>;
+}
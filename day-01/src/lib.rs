@@ -1,2 +1,26 @@
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<&'a str>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -1,19 +1,27 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
-pub fn process(input: &str) -> Result<String> {
-    let output = input
-        .lines()
+use crate::Day01;
+
+#[instrument(name = "solve", skip_all)]
+pub fn solve(lines: &[&str]) -> Result<Answer> {
+    let output = lines
+        .iter()
         .map(|line| {
             let mut nums = line.chars().filter_map(|c| c.to_digit(10));
             let first = nums.next().expect("to start with a number");
-            match nums.last() {
+            match nums.next_back() {
                 Some(last) => first * 10 + last,
                 None => first * 10 + first,
             }
         })
-        .sum::<u32>()
-        .to_string();
-    Ok(output)
+        .sum::<u32>();
+    Ok(output.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day01::parse(input)?)
 }
 
 #[cfg(test)]
@@ -26,7 +34,7 @@ mod tests {
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet";
-        assert_eq!("142", process(input)?);
+        assert_eq!(process(input)?, 142.into());
         Ok(())
     }
 }
@@ -1,4 +1,8 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
+
+use crate::Day01;
 
 pub fn parse_line(line: &str) -> u32 {
     let mut numbers = (0..line.len()).filter_map(|index| {
@@ -17,15 +21,20 @@ pub fn parse_line(line: &str) -> u32 {
         }
     });
     let first = numbers.next().expect("to start with a number");
-    match numbers.last() {
+    match numbers.next_back() {
         Some(last) => first * 10 + last,
         None => first * 10 + first,
     }
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let output = input.lines().map(parse_line).sum::<u32>().to_string();
-    Ok(output)
+#[instrument(name = "solve", skip_all)]
+pub fn solve(lines: &[&str]) -> Result<Answer> {
+    let output = lines.iter().map(|line| parse_line(line)).sum::<u32>();
+    Ok(output.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day01::parse(input)?)
 }
 
 #[cfg(test)]
@@ -49,7 +58,7 @@ xtwone3four
 4nineeightseven2
 zoneight234
 7pqrstsixteen";
-        assert_eq!("281", process(input)?);
+        assert_eq!(process(input)?, 281.into());
         Ok(())
     }
 }
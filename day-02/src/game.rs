@@ -1,8 +1,17 @@
+use aoc_common::parse::{literal, number, parse, IResult, ParseError};
 use derive_more::{From, Sum};
+use nom::{
+    branch::alt,
+    character::complete::char,
+    combinator::{cut, map},
+    error::context,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+};
 use std::cmp::max;
-use std::{ops::Add, str::FromStr};
+use std::{fmt, ops::Add, str::FromStr};
 
-#[derive(Debug, Default, PartialEq, Sum, From)]
+#[derive(Debug, Default, Clone, PartialEq, Sum, From)]
 pub struct Round {
     pub red: usize,
     pub green: usize,
@@ -10,22 +19,50 @@ pub struct Round {
 }
 
 impl FromStr for Round {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Round, ()> {
-        let mut round = Round::default();
-        s.split(", ")
-            .filter_map(|cube| cube.split_once(' '))
-            .for_each(|(value, color)| {
-                let value: usize = value.parse().expect("should be a number");
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Round, Self::Err> {
+        parse(s, round)
+    }
+}
+
+/// Parse the `<count> <color>` cubes of a round
+pub fn round(input: &str) -> IResult<'_, Round> {
+    let color = context(
+        "a color",
+        alt((literal("red"), literal("green"), literal("blue"))),
+    );
+    let cube = separated_pair(number, cut(char(' ')), cut(color));
+    map(separated_list1(literal(", "), cube), |cubes| {
+        cubes
+            .into_iter()
+            .fold(Round::default(), |mut round, (value, color)| {
                 match color {
                     "red" => round.red = value,
                     "green" => round.green = value,
-                    "blue" => round.blue = value,
-                    _ => {}
+                    _ => round.blue = value,
                 }
-            });
-        Ok(round)
+                round
+            })
+    })(input)
+}
+
+/// Write the colors that have cubes, as in `4 red, 3 blue`, or `0 red` for an empty round
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<_> = [
+            (self.red, "red"),
+            (self.green, "green"),
+            (self.blue, "blue"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, color)| format!("{count} {color}"))
+        .collect();
+        match cubes.is_empty() {
+            true => write!(f, "0 red"),
+            false => write!(f, "{}", cubes.join(", ")),
+        }
     }
 }
 
@@ -54,25 +91,41 @@ pub struct Game {
 }
 
 impl FromStr for Game {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Game, ()> {
-        let (raw_game, raw_rounds) = s.split_once(": ").ok_or(())?;
-        let id: usize = raw_game
-            .strip_prefix("Game ")
-            .ok_or(())?
-            .parse()
-            .map_err(|_| ())?;
-        let rounds: Vec<Round> = raw_rounds
-            .split("; ")
-            .filter_map(|raw_round| raw_round.parse().ok())
-            .collect();
-        Ok(Game { id, rounds })
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Game, Self::Err> {
+        parse(s, game)
     }
 }
 
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rounds: Vec<_> = self.rounds.iter().map(Round::to_string).collect();
+        write!(f, "Game {}: {}", self.id, rounds.join("; "))
+    }
+}
+
+/// Parse a `Game <id>: <round>; <round>` line
+pub fn game(input: &str) -> IResult<'_, Game> {
+    context(
+        "game",
+        map(
+            preceded(
+                literal("Game "),
+                cut(separated_pair(
+                    number,
+                    literal(": "),
+                    separated_list1(literal("; "), round),
+                )),
+            ),
+            |(id, rounds)| Game { id, rounds },
+        ),
+    )(input)
+}
+
 #[cfg(test)]
 mod tests {
+    use proptest::{collection::vec, prelude::*};
     use rstest::rstest;
 
     use super::*;
@@ -115,4 +168,38 @@ mod tests {
             input.parse().unwrap()
         );
     }
+
+    #[test]
+    fn test_game_display() {
+        let input = "Game 1: 4 red, 3 blue; 1 red, 2 green, 6 blue; 0 red";
+        assert_eq!(input.parse::<Game>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_game_parse_error() {
+        let error = "Game 1: 3 blue, 4 purple".parse::<Game>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid game: expected a color at line 1, column 19"
+        );
+    }
+
+    prop_compose! {
+        fn arb_round()(red in 0..100usize, green in 0..100usize, blue in 0..100usize) -> Round {
+            Round { red, green, blue }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_round_trip(round in arb_round()) {
+            prop_assert_eq!(round.to_string().parse::<Round>()?, round);
+        }
+
+        #[test]
+        fn test_game_round_trip(id in 1..1000usize, rounds in vec(arb_round(), 1..8)) {
+            let game = Game { id, rounds };
+            prop_assert_eq!(game.to_string().parse::<Game>()?, game);
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use rand::Rng;
+
+use crate::game::{Game, Round};
+
+/// Generate `size` games of up to 6 rounds, with up to 20 cubes of each color
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let rounds = (0..rng.gen_range(1..=6)).map(|_| round(rng)).collect();
+            format!("{}\n", Game { id, rounds })
+        })
+        .collect()
+}
+
+/// Draw a round where each color shows up two times out of three, at least one always does
+fn round<R: Rng + ?Sized>(rng: &mut R) -> Round {
+    loop {
+        let mut count = || match rng.gen_bool(2.0 / 3.0) {
+            true => rng.gen_range(1..=20),
+            false => 0,
+        };
+        let round = Round {
+            red: count(),
+            green: count(),
+            blue: count(),
+        };
+        if round != Round::default() {
+            return round;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 50));
+        assert_eq!(input.lines().count(), 50);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
@@ -1,3 +1,38 @@
-mod game;
+use anyhow::Result;
+use aoc_common::{
+    parse::{lines, parse},
+    Answer, Solver,
+};
+use tracing::instrument;
+
+use crate::game::{game, Game};
+
+pub mod game;
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Game>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input, lines(game)).map_err(|e| {
+            e.into_diagnostic(input)
+                .with_help("games look like `Game 1: 3 blue, 4 red; 2 green`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
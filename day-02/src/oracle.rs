@@ -0,0 +1,65 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+
+use crate::game::Round;
+use crate::Day02;
+
+/// Counts of each color in the round, as red, green and blue
+fn counts(round: &Round) -> [usize; 3] {
+    [round.red, round.green, round.blue]
+}
+
+/// Check every color of every round against the bag of 12 red, 13 green and 14 blue
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for game in Day02::parse(input)? {
+        let mut possible = true;
+        for round in &game.rounds {
+            for (count, limit) in counts(round).into_iter().zip([12, 13, 14]) {
+                if count > limit {
+                    possible = false;
+                }
+            }
+        }
+        if possible {
+            total += game.id;
+        }
+    }
+    Ok(total.into())
+}
+
+/// Try bags of growing size for each color until one holds every round
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for game in Day02::parse(input)? {
+        let mut power = 1;
+        for color in 0..3 {
+            let mut cubes = 0;
+            while game.rounds.iter().any(|round| counts(round)[color] > cubes) {
+                cubes += 1;
+            }
+            power *= cubes;
+        }
+        total += power;
+    }
+    Ok(total.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(part1(input)?, 8.into());
+        assert_eq!(part2(input)?, 2286.into());
+        Ok(())
+    }
+}
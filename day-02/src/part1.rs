@@ -1,16 +1,23 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::game::{Game, Round};
+use crate::Day02;
 
-pub fn process(input: &str) -> Result<String> {
+#[instrument(name = "solve", skip_all)]
+pub fn solve(games: &[Game]) -> Result<Answer> {
     let bag = Round::from((12, 13, 14));
-    let total: usize = input
-        .lines()
-        .filter_map(|line| line.parse().ok())
-        .filter(|game: &Game| game.rounds.iter().all(|round| bag.contains(round)))
+    let total: usize = games
+        .iter()
+        .filter(|game| game.rounds.iter().all(|round| bag.contains(round)))
         .map(|game| game.id)
         .sum();
-    Ok(total.to_string())
+    Ok(total.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day02::parse(input)?)
 }
 
 #[cfg(test)]
@@ -25,7 +32,7 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
         ";
-        assert_eq!("8", process(input)?);
+        assert_eq!(process(input)?, 8.into());
         Ok(())
     }
 }
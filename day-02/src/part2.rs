@@ -1,15 +1,22 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::game::{Game, Round};
+use crate::Day02;
 
-pub fn process(input: &str) -> Result<String> {
-    let total: usize = input
-        .lines()
-        .filter_map(|line| line.parse().ok())
-        .map(|game: Game| game.rounds.into_iter().sum::<Round>())
+#[instrument(name = "solve", skip_all)]
+pub fn solve(games: &[Game]) -> Result<Answer> {
+    let total: usize = games
+        .iter()
+        .map(|game| game.rounds.iter().cloned().sum::<Round>())
         .map(|round: Round| round.red * round.green * round.blue)
         .sum();
-    Ok(total.to_string())
+    Ok(total.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day02::parse(input)?)
 }
 
 #[cfg(test)]
@@ -24,7 +31,7 @@ Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
         ";
-        assert_eq!("2286", process(input)?);
+        assert_eq!(process(input)?, 2286.into());
         Ok(())
     }
 }
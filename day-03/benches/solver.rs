@@ -0,0 +1,15 @@
+use aoc_common::bench::bench_solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day_03::Day03;
+
+fn bench(c: &mut Criterion) {
+    bench_solver::<Day03>(
+        c,
+        include_str!("../example.txt"),
+        include_str!("../example.txt"),
+    );
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use rand::{seq::SliceRandom, Rng};
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// Generate a `size` by `size` schematic of numbers up to 999 with symbols in between
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size + 1);
+            while row.len() < size {
+                match rng.gen_range(0..10) {
+                    0 | 1 => {
+                        // Numbers are followed by a `.` so that they never run into each other
+                        let number = rng.gen_range(1..1000).to_string();
+                        if number.len() < size - row.len() {
+                            row.push_str(&number);
+                        }
+                        row.push('.');
+                    }
+                    2 => row.push(*SYMBOLS.choose(rng).unwrap() as char),
+                    _ => row.push('.'),
+                }
+            }
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 50));
+        assert!(input.lines().all(|line| line.len() == 50));
+        assert_eq!(input.lines().count(), 50);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
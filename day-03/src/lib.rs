@@ -1,3 +1,31 @@
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
+
+use crate::schematic::Schematic;
+
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
-mod schematic;
+pub mod schematic;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Schematic;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
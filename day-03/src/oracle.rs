@@ -0,0 +1,111 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::Result;
+use aoc_common::Answer;
+
+/// A number of the schematic, on row `y` from column `start` to `end` excluded
+struct Number {
+    value: u32,
+    y: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Number {
+    /// Whether the cell is next to a digit of the number, diagonals included
+    fn touches(&self, x: usize, y: usize) -> bool {
+        y + 1 >= self.y && y <= self.y + 1 && x + 1 >= self.start && x <= self.end
+    }
+}
+
+/// Scan every row for runs of digits
+fn numbers(grid: &[Vec<char>]) -> Vec<Number> {
+    let mut numbers = vec![];
+    for (y, row) in grid.iter().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if row[x].is_ascii_digit() {
+                let (start, mut value) = (x, 0);
+                while let Some(digit) = row.get(x).and_then(|c| c.to_digit(10)) {
+                    value = value * 10 + digit;
+                    x += 1;
+                }
+                numbers.push(Number {
+                    value,
+                    y,
+                    start,
+                    end: x,
+                });
+            } else {
+                x += 1;
+            }
+        }
+    }
+    numbers
+}
+
+fn grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Every cell holding the given kind of symbol
+fn cells(grid: &[Vec<char>], symbol: impl Fn(char) -> bool) -> Vec<(usize, usize)> {
+    let mut cells = vec![];
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if symbol(c) {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// Sum the numbers that touch any symbol, checking them all
+pub fn part1(input: &str) -> Result<Answer> {
+    let grid = grid(input);
+    let symbols = cells(&grid, |c| c != '.' && !c.is_ascii_digit());
+    let total: u32 = numbers(&grid)
+        .iter()
+        .filter(|number| symbols.iter().any(|&(x, y)| number.touches(x, y)))
+        .map(|number| number.value)
+        .sum();
+    Ok(total.into())
+}
+
+/// Sum the products of the numbers touching each `*` touched by exactly two
+pub fn part2(input: &str) -> Result<Answer> {
+    let grid = grid(input);
+    let numbers = numbers(&grid);
+    let mut total = 0;
+    for (x, y) in cells(&grid, |c| c == '*') {
+        let touching: Vec<_> = numbers.iter().filter(|n| n.touches(x, y)).collect();
+        if let [a, b] = touching[..] {
+            total += a.value * b.value;
+        }
+    }
+    Ok(total.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = r"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        assert_eq!(part1(input)?, 4361.into());
+        assert_eq!(part2(input)?, 467835.into());
+        assert_eq!(part1("..*\n.12")?, 12.into());
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::schematic::Schematic;
+use crate::Day03;
 
-pub fn process(input: &str) -> Result<String> {
-    let schematic: Schematic = input.parse().expect("to be valid schematic");
+#[instrument(name = "solve", skip_all)]
+pub fn solve(schematic: &Schematic) -> Result<Answer> {
     Ok(schematic
         .parts
-        .into_iter()
+        .iter()
         .filter(|part| {
             part.points
                 .intersection(&schematic.symbols)
@@ -15,7 +18,11 @@ pub fn process(input: &str) -> Result<String> {
         })
         .map(|point| point.value)
         .sum::<u32>()
-        .to_string())
+        .into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day03::parse(input)?)
 }
 
 #[cfg(test)]
@@ -34,14 +41,14 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!("4361", process(input)?);
+        assert_eq!(process(input)?, 4361.into());
         Ok(())
     }
 
     #[test]
     fn test_process_2() -> Result<()> {
         let input = "..*\n.12";
-        assert_eq!("12", process(input)?);
+        assert_eq!(process(input)?, 12.into());
         Ok(())
     }
 }
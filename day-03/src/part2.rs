@@ -1,9 +1,12 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::schematic::Schematic;
+use crate::Day03;
 
-pub fn process(input: &str) -> Result<String> {
-    let schematic: Schematic = input.parse().expect("to be valid schematic");
+#[instrument(name = "solve", skip_all)]
+pub fn solve(schematic: &Schematic) -> Result<Answer> {
     let mut ratios = 0;
     for gear in &schematic.gears {
         let mut matches = vec![];
@@ -18,7 +21,11 @@ pub fn process(input: &str) -> Result<String> {
         }
     }
 
-    Ok(ratios.to_string())
+    Ok(ratios.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day03::parse(input)?)
 }
 
 #[cfg(test)]
@@ -37,7 +44,7 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!("467835", process(input)?);
+        assert_eq!(process(input)?, 467835.into());
         Ok(())
     }
 }
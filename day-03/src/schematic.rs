@@ -1,8 +1,7 @@
 use std::{collections::HashSet, str::FromStr};
 
-use anyhow::Result;
-
-type Point = (i32, i32);
+use anyhow::{Error, Result};
+use aoc_grid::{Grid, Point};
 
 #[derive(Debug)]
 pub struct Part {
@@ -14,7 +13,7 @@ impl Part {
     fn new(origin: Point, c: char) -> Self {
         let value = c.to_digit(10).expect("to be a digit");
         let points = itertools::iproduct!((-1..=1), (-1..=1))
-            .map(|(x, y)| (origin.0 + x, origin.1 + y))
+            .map(|(x, y)| origin + Point::new(x, y))
             .collect();
 
         Part { value, points }
@@ -25,7 +24,7 @@ impl Part {
         let value = c.to_digit(10).expect("to be a digit");
         self.value = self.value * 10 + value;
         self.points.extend(
-            itertools::iproduct!((-1..=1), (-1..=1)).map(|(x, y)| (origin.0 + x, origin.1 + y)),
+            itertools::iproduct!((-1..=1), (-1..=1)).map(|(x, y)| origin + Point::new(x, y)),
         )
     }
 }
@@ -37,19 +36,21 @@ pub struct Schematic {
 }
 
 impl FromStr for Schematic {
-    type Err = ();
+    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, ()> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.parse()?;
         let mut parts = vec![];
         let mut symbols = HashSet::new();
         let mut gears = HashSet::new();
-        for (x, line) in s.lines().enumerate() {
+        for (y, row) in grid.rows().enumerate() {
             let mut cur_part: Option<Part> = None;
-            for (y, c) in line.chars().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let point = Point::new(x as i64, y as i64);
                 if c.is_ascii_digit() {
                     match cur_part {
-                        Some(ref mut part) => part.push((x as i32, y as i32), c),
-                        None => cur_part = Some(Part::new((x as i32, y as i32), c)),
+                        Some(ref mut part) => part.push(point, c),
+                        None => cur_part = Some(Part::new(point, c)),
                     }
                 } else {
                     if let Some(part) = cur_part {
@@ -57,9 +58,9 @@ impl FromStr for Schematic {
                         cur_part = None
                     }
                     if c != '.' {
-                        symbols.insert((x as i32, y as i32));
+                        symbols.insert(point);
                         if c == '*' {
-                            gears.insert((x as i32, y as i32));
+                            gears.insert(point);
                         }
                     }
                 }
@@ -1,48 +1,130 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::Result;
+use aoc_common::parse::{literal, number, numbers, parse, IResult, ParseError};
+use nom::{
+    character::complete::{char, space1},
+    combinator::{cut, map},
+    error::context,
+    sequence::{delimited, preceded, tuple},
+};
 
 #[derive(Debug, PartialEq)]
 pub struct Card {
     pub id: usize,
-    pub count: usize,
+    pub winnings: Vec<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    /// Return how many of the numbers are winning ones
+    pub fn count(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|n| self.winnings.contains(n))
+            .count()
+    }
 }
 
 impl FromStr for Card {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Card, Self::Err> {
-        let (card, rest) = s.split_once(": ").expect("to be formated");
-        let id = card
-            .split_whitespace()
-            .nth(1)
-            .ok_or("failed to parse card id")?
-            .parse::<usize>()
-            .map_err(|e| format!("Card id is not a number: {e}"))?;
-        let (winnings, numbers) = rest.split_once(" | ").ok_or("Missing | separetor")?;
-        let winnings: Vec<u32> = parse_whitespace_spec(winnings);
-        let numbers: Vec<u32> = parse_whitespace_spec(numbers);
-        let count = numbers.iter().filter(|n| winnings.contains(n)).count();
-
-        Ok(Card { id, count })
+        parse(s, card)
+    }
+}
+
+/// Write the card as in the puzzle, with the numbers aligned
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        write!(
+            f,
+            "Card {:>3}: {} | {}",
+            self.id,
+            numbers(&self.winnings),
+            numbers(&self.numbers)
+        )
     }
 }
 
-/// Parse space separated items
-fn parse_whitespace_spec<T: FromStr>(s: &str) -> Vec<T> {
-    s.split_whitespace()
-        .filter_map(|n| n.parse().ok())
-        .collect()
+/// Parse a `Card <id>: <winnings> | <numbers>` line
+pub fn card(input: &str) -> IResult<'_, Card> {
+    context(
+        "card",
+        map(
+            preceded(
+                literal("Card"),
+                cut(tuple((
+                    delimited(space1, number, char(':')),
+                    preceded(space1, numbers),
+                    preceded(delimited(space1, char('|'), space1), numbers),
+                ))),
+            ),
+            |(id, winnings, numbers)| Card {
+                id,
+                winnings,
+                numbers,
+            },
+        ),
+    )(input)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::parse::lines;
+    use proptest::{collection::vec, prelude::*};
+
     use super::*;
 
     #[test]
     fn test_parse_card() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
-        let card = Card { id: 1, count: 4 };
+        let card = Card {
+            id: 1,
+            winnings: vec![41, 48, 83, 86, 17],
+            numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        };
         assert_eq!(input.parse::<Card>().unwrap(), card);
+        assert_eq!(card.count(), 4);
+    }
+
+    #[test]
+    fn test_display_card() {
+        let input = "Card   1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        assert_eq!(input.parse::<Card>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_card_error() {
+        let error = "Card 1: 41 48 83 / 83 86".parse::<Card>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid card: expected `|` at line 1, column 18"
+        );
+
+        let input = "Card 1: 41 | 41\nCard 2: 83 | x";
+        let error = parse(input, lines(card)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid card: expected a number at line 2, column 14"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_card_round_trip(
+            id in 1..1000usize,
+            winnings in vec(1..100u32, 1..10),
+            numbers in vec(1..100u32, 1..25),
+        ) {
+            let card = Card { id, winnings, numbers };
+            prop_assert_eq!(card.to_string().parse::<Card>()?, card);
+        }
     }
 }
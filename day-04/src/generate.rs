@@ -0,0 +1,57 @@
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+/// Generate `size` scratchcards of 10 winning numbers and 25 numbers, from 1 to 99
+///
+/// Three cards out of four win nothing, so that the copies won in part 2 stay in the
+/// thousands, and no card wins copies past the end of the table.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let max = (size - id).min(4);
+            let count = match max > 0 && rng.gen_bool(0.25) {
+                true => rng.gen_range(1..=max),
+                false => 0,
+            };
+            // The winnings, then the other numbers
+            let picked: Vec<_> = index::sample(rng, 99, 35)
+                .into_iter()
+                .map(|n| n + 1)
+                .collect();
+            let (winnings, others) = picked.split_at(10);
+            let mut numbers: Vec<_> = winnings[..count]
+                .iter()
+                .chain(&others[count..])
+                .copied()
+                .collect();
+            numbers.shuffle(rng);
+
+            let join = |numbers: &[usize]| {
+                let numbers: Vec<_> = numbers.iter().map(|n| format!("{n:>2}")).collect();
+                numbers.join(" ")
+            };
+            format!("Card {id:>3}: {} | {}\n", join(winnings), join(&numbers))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 200);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 200));
+        assert_eq!(input.lines().count(), 200);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
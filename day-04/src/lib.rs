@@ -1,3 +1,38 @@
-mod card;
+use anyhow::Result;
+use aoc_common::{
+    parse::{lines, parse},
+    Answer, Solver,
+};
+use tracing::instrument;
+
+use crate::card::{card, Card};
+
+pub mod card;
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Card>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input, lines(card)).map_err(|e| {
+            e.into_diagnostic(input)
+                .with_help("cards look like `Card 1: 41 48 83 | 83 86 6`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
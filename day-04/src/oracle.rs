@@ -0,0 +1,63 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Count the numbers of each card found among its winning numbers
+fn wins(input: &str) -> Result<Vec<usize>> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(':').ok_or(anyhow!("no `:` in {line}"))?;
+            let (winnings, numbers) = numbers.split_once('|').ok_or(anyhow!("no `|` in {line}"))?;
+            let winnings: Vec<_> = winnings.split_whitespace().collect();
+            Ok(numbers
+                .split_whitespace()
+                .filter(|n| winnings.contains(n))
+                .count())
+        })
+        .collect()
+}
+
+/// Double the points of a card for each winning number after the first
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for wins in wins(input)? {
+        let mut points = 0;
+        for _ in 0..wins {
+            points = if points == 0 { 1 } else { points * 2 };
+        }
+        total += points;
+    }
+    Ok(total.into())
+}
+
+/// Scratch every copy of every card one at a time, each winning copies of the next ones
+pub fn part2(input: &str) -> Result<Answer> {
+    let wins = wins(input)?;
+    let mut pile: Vec<usize> = (0..wins.len()).collect();
+    let mut scratched = 0;
+    while let Some(card) = pile.pop() {
+        scratched += 1;
+        pile.extend((card + 1..=card + wins[card]).filter(|&copy| copy < wins.len()));
+    }
+    Ok(scratched.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(part1(input)?, 13.into());
+        assert_eq!(part2(input)?, 30.into());
+        Ok(())
+    }
+}
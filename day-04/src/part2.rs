@@ -1,23 +1,31 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::card::Card;
+use crate::Day04;
 
-/// Recursivly returs the number of winning cards form the given card.
-fn process_card(card: &Card, cards: &[Card]) -> usize {
+/// Recursivly returs the number of winning cards form the card at the given index,
+/// from how many numbers each card wins.
+fn process_card(index: usize, counts: &[usize]) -> usize {
     let mut res = 1;
-    for id in card.id..card.id + card.count {
-        res += process_card(&cards[id], cards);
+    for next in index + 1..=index + counts[index] {
+        res += process_card(next, counts);
     }
     res
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let cards: Vec<Card> = input.lines().map(|line| line.parse().unwrap()).collect();
-    Ok(cards
-        .iter()
-        .map(|card| process_card(card, cards.as_slice()))
+#[instrument(name = "solve", skip_all)]
+pub fn solve(cards: &[Card]) -> Result<Answer> {
+    let counts: Vec<_> = cards.iter().map(Card::count).collect();
+    Ok((0..cards.len())
+        .map(|index| process_card(index, &counts))
         .sum::<usize>()
-        .to_string())
+        .into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day04::parse(input)?)
 }
 
 #[cfg(test)]
@@ -26,12 +34,7 @@ mod tests {
 
     #[test]
     fn test_process_card() {
-        let cards = vec![
-            Card { id: 1, count: 2 },
-            Card { id: 2, count: 1 },
-            Card { id: 3, count: 0 },
-        ];
-        assert_eq!(process_card(&cards[0], cards.as_slice()), 4)
+        assert_eq!(process_card(0, &[2, 1, 0]), 4)
     }
 
     #[test]
@@ -42,7 +45,7 @@ Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        assert_eq!(process(input).unwrap(), "30");
+        assert_eq!(process(input).unwrap(), 30.into());
         Ok(())
     }
 }
@@ -0,0 +1,91 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// Categories going through the maps, from seed to location
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Numbers of the almanac are below this bound, as in the puzzle
+const MAX: u64 = 1 << 32;
+
+/// Generate an almanac whose 10 seed ranges hold `size` seeds, the work of part 2
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut input = "seeds:".to_string();
+    for length in split(rng, size.max(1) as u64, 10) {
+        input += &format!(" {} {length}", rng.gen_range(0..MAX - length));
+    }
+    for pair in CATEGORIES.windows(2) {
+        input += &format!("\n\n{}-to-{} map:", pair[0], pair[1]);
+        for (dest, source, length) in map(rng) {
+            input += &format!("\n{dest} {source} {length}");
+        }
+    }
+    input + "\n"
+}
+
+/// Split `total` in `parts` random lengths
+fn split<R: Rng + ?Sized>(rng: &mut R, total: u64, parts: usize) -> Vec<u64> {
+    let mut cuts: Vec<_> = (1..parts).map(|_| rng.gen_range(0..=total)).collect();
+    cuts.extend([0, total]);
+    cuts.sort_unstable();
+    cuts.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// Cut the numbers in pieces, shuffling them around, as `(dest, source, length)` ranges
+///
+/// A few pieces are left out, mapping their numbers to themselves.
+fn map<R: Rng + ?Sized>(rng: &mut R) -> Vec<(u64, u64, u64)> {
+    let parts = rng.gen_range(5..=40);
+    let lengths = split(rng, MAX, parts);
+    let starts = |order: &[usize]| {
+        let mut starts = vec![0; order.len()];
+        order.iter().fold(0, |start, &i| {
+            starts[i] = start;
+            start + lengths[i]
+        });
+        starts
+    };
+    let sources = starts(&(0..parts).collect::<Vec<_>>());
+    let mut order: Vec<_> = (0..parts).collect();
+    order.shuffle(rng);
+    let dests = starts(&order);
+
+    let mut ranges: Vec<_> = (0..parts)
+        .filter(|&i| lengths[i] > 0 && !rng.gen_bool(0.2))
+        .map(|i| (dests[i], sources[i], lengths[i]))
+        .collect();
+    if ranges.is_empty() {
+        ranges.push((sources[0], sources[0], lengths[0]));
+    }
+    ranges.shuffle(rng);
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{map::Almanac, part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 1000);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 1000));
+        let almanac: Almanac = input.parse()?;
+        assert_eq!(almanac.seeds.len(), 20);
+        assert_eq!(almanac.seeds.iter().skip(1).step_by(2).sum::<u64>(), 1000);
+        assert_eq!(almanac.maps.len(), 7);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
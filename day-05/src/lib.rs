@@ -1,3 +1,37 @@
-mod map;
+use anyhow::Result;
+use aoc_common::{parse::ParseError, Answer, Solver};
+use tracing::instrument;
+
+use crate::map::Almanac;
+
+pub mod generate;
+pub mod map;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = Almanac;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse().map_err(|e: ParseError| {
+            e.into_diagnostic(input)
+                .with_help(
+                    "the seeds line is followed by maps of `<dest> <source> <length>` ranges",
+                )
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
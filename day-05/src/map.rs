@@ -1,53 +1,154 @@
 use std::ops::Range;
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::Result;
+use aoc_common::parse::{lines, literal, number, parse, IResult, ParseError};
+use nom::{
+    character::complete::{alpha1, line_ending, space1},
+    combinator::{cut, flat_map, map, map_res},
+    error::context,
+    multi::{count, separated_list1},
+    sequence::{pair, preceded, separated_pair, terminated},
+};
 
 #[derive(Debug, PartialEq)]
-pub struct Map(pub Vec<(Range<u64>, Range<u64>)>);
+pub struct Map {
+    /// Category of the source numbers, such as `seed`
+    pub from: String,
+    /// Category of the destination numbers, such as `soil`
+    pub to: String,
+    /// Source and destination ranges
+    pub ranges: Vec<(Range<u64>, Range<u64>)>,
+}
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Map(s
-            .lines()
-            .skip(1)
-            .map(|line| {
-                let mut iter = line.split(char::is_whitespace);
-                let dest: u64 = iter
-                    .next()
-                    .expect("to have a dest")
-                    .parse()
-                    .expect("dest to be a number");
-                let source: u64 = iter
-                    .next()
-                    .expect("to have a source")
-                    .parse()
-                    .expect("source to be a number");
-                let range: u64 = iter
-                    .next()
-                    .expect("to have a range")
-                    .parse()
-                    .expect("range to be a number");
-                (source..source + range, dest..dest + range)
-            })
-            .collect()))
+        parse(s, map_section)
     }
 }
 
+/// Write the `<dest> <source> <length>` ranges under their `<from>-to-<to> map:` header
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.from, self.to)?;
+        for (source, dest) in &self.ranges {
+            write!(
+                f,
+                "\n{} {} {}",
+                dest.start,
+                source.start,
+                source.end - source.start
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a `<dest> <source> <length>` line into the source and destination ranges,
+/// failing on a length that overflows either range
+fn range(input: &str) -> IResult<'_, (Range<u64>, Range<u64>)> {
+    let ranges = |(dest, source): (u64, u64)| {
+        let length = map_res(number::<u64>, move |length| {
+            let ends = source.checked_add(length).zip(dest.checked_add(length));
+            ends.map(|(source_end, dest_end)| (source..source_end, dest..dest_end))
+                .ok_or("overflow")
+        });
+        cut(preceded(space1, length))
+    };
+    context(
+        "range",
+        flat_map(pair(number, cut(preceded(space1, number))), ranges),
+    )(input)
+}
+
+/// Parse a `<from>-to-<to> map:` header followed by its ranges
+fn map_section(input: &str) -> IResult<'_, Map> {
+    let names = separated_pair(alpha1, literal("-to-"), alpha1);
+    let header = context("map header", terminated(names, literal(" map:")));
+    map(
+        pair(terminated(header, line_ending), cut(lines(range))),
+        |((from, to), ranges)| Map {
+            from: from.to_string(),
+            to: to.to_string(),
+            ranges,
+        },
+    )(input)
+}
+
+/// Parse the seeds, which come in pairs of a start and a length for part 2
+fn seeds(input: &str) -> IResult<'_, Vec<u64>> {
+    let pairs = separated_list1(space1, pair(number, cut(preceded(space1, number))));
+    context(
+        "seeds",
+        preceded(
+            literal("seeds: "),
+            cut(map(pairs, |pairs| {
+                pairs.into_iter().flat_map(|(a, b)| [a, b]).collect()
+            })),
+        ),
+    )(input)
+}
+
+/// Parse the seeds followed by the maps, separated by blank lines
+fn almanac(input: &str) -> IResult<'_, Almanac> {
+    map(
+        separated_pair(
+            seeds,
+            count(line_ending, 2),
+            separated_list1(count(line_ending, 2), map_section),
+        ),
+        |(seeds, maps)| Almanac { seeds, maps },
+    )(input)
+}
+
 impl Map {
     /// Return the mapped destination
     pub fn get(&self, key: u64) -> u64 {
-        match self.0.iter().find(|range| range.0.contains(&key)) {
+        match self.ranges.iter().find(|range| range.0.contains(&key)) {
             Some((source, dest)) => dest.start + (key - source.start),
             None => key,
         }
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, almanac)
+    }
+}
+
+/// Write the seeds then the maps, as in the puzzle
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seeds: Vec<_> = self.seeds.iter().map(u64::to_string).collect();
+        write!(f, "seeds: {}", seeds.join(" "))?;
+        for map in &self.maps {
+            write!(f, "\n\n{map}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Return the location of a seed after going through every map
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |source, map| map.get(source))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use proptest::{collection::vec, prelude::*};
+
     use super::*;
 
     #[test]
@@ -57,7 +158,74 @@ mod tests {
 52 50 48";
         assert_eq!(
             input.parse::<Map>().unwrap(),
-            Map(vec![(98..100, 50..52), (50..98, 52..100)])
+            Map {
+                from: "seed".to_string(),
+                to: "soil".to_string(),
+                ranges: vec![(98..100, 50..52), (50..98, 52..100)]
+            }
+        );
+    }
+
+    #[test]
+    fn test_display_almanac() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37";
+        assert_eq!(input.parse::<Almanac>().unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_almanac_error() {
+        let input = r"seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50";
+        let error = input.parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid range: expected a space at line 5, column 6"
+        );
+
+        let error = "seed-to-soil map:\n50 98 2\n0 18446744073709551615 2"
+            .parse::<Map>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid range: number out of range at line 3, column 24"
         );
+
+        let error = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid seeds: expected a space at line 1, column 16"
+        );
+    }
+
+    prop_compose! {
+        fn arb_range()(dest in 0..1u64 << 40, source in 0..1u64 << 40, length in 0..1u64 << 20)
+            -> (Range<u64>, Range<u64>) {
+            (source..source + length, dest..dest + length)
+        }
+    }
+
+    prop_compose! {
+        fn arb_map()(from in "[a-z]{1,12}", to in "[a-z]{1,12}", ranges in vec(arb_range(), 1..10))
+            -> Map {
+            Map { from, to, ranges }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_map_round_trip(map in arb_map()) {
+            prop_assert_eq!(map.to_string().parse::<Map>()?, map);
+        }
+
+        #[test]
+        fn test_almanac_round_trip(seeds in vec(any::<[u64; 2]>(), 1..10), maps in vec(arb_map(), 1..8)) {
+            let almanac = Almanac { seeds: seeds.concat(), maps };
+            prop_assert_eq!(almanac.to_string().parse::<Almanac>()?, almanac);
+        }
     }
 }
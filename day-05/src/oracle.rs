@@ -0,0 +1,89 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
+
+use crate::map::Almanac;
+use crate::Day05;
+
+/// Follow a seed through the maps, looking it up in every range of each map
+fn location(almanac: &Almanac, seed: u64) -> u64 {
+    let mut value = seed;
+    for map in &almanac.maps {
+        for (source, dest) in &map.ranges {
+            if source.start <= value && value < source.end {
+                value = dest.start + (value - source.start);
+                break;
+            }
+        }
+    }
+    value
+}
+
+/// Lowest location of the seeds
+pub fn part1(input: &str) -> Result<Answer> {
+    let almanac = Day05::parse(input)?;
+    let locations = almanac.seeds.iter().map(|&seed| location(&almanac, seed));
+    Ok(locations.min().ok_or(anyhow!("no seeds"))?.into())
+}
+
+/// Lowest location of every seed of every range, one seed at a time
+pub fn part2(input: &str) -> Result<Answer> {
+    let almanac = Day05::parse(input)?;
+    let mut lowest = None;
+    for pair in almanac.seeds.chunks(2) {
+        let &[start, length] = pair else {
+            return Err(anyhow!("seed {} has no range length", pair[0]));
+        };
+        for seed in start..start + length {
+            let location = location(&almanac, seed);
+            lowest = Some(lowest.map_or(location, |lowest: u64| lowest.min(location)));
+        }
+    }
+    Ok(lowest.ok_or(anyhow!("no seeds"))?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        assert_eq!(part1(input)?, 35.into());
+        assert_eq!(part2(input)?, 46.into());
+        Ok(())
+    }
+}
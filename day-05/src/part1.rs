@@ -1,27 +1,46 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::{debug, instrument};
 
-use crate::map::Map;
+use crate::map::Almanac;
+use crate::Day05;
 
-pub fn process(input: &str) -> Result<String> {
-    let (seeds, maps) = input.split_once("\n\n").unwrap();
-    let seeds: Vec<_> = seeds
-        .strip_prefix("seeds: ")
-        .unwrap()
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect();
-    let maps: Vec<Map> = maps.split("\n\n").map(|map| map.parse().unwrap()).collect();
-    Ok(seeds
-        .into_iter()
-        .map(|seed| maps.iter().fold(seed, |source, map| map.get(source)))
+#[instrument(name = "solve", skip_all)]
+pub fn solve(almanac: &Almanac) -> Result<Answer> {
+    for (stage, map) in almanac.maps.iter().enumerate() {
+        // Only walked through the maps again when the event is enabled
+        let lowest = || {
+            let maps = &almanac.maps[..=stage];
+            almanac
+                .seeds
+                .iter()
+                .map(|&seed| maps.iter().fold(seed, |source, map| map.get(source)))
+                .min()
+        };
+        debug!(
+            stage,
+            ranges = map.ranges.len(),
+            lowest = lowest(),
+            "mapped"
+        );
+    }
+    Ok(almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.location(*seed))
         .min()
         .expect("to have a min")
-        .to_string())
+        .into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day05::parse(input)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::map::Map;
 
     #[test]
     fn test_parse_seed_to_soil() {
@@ -30,7 +49,11 @@ mod tests {
 52 50 48";
         assert_eq!(
             input.parse::<Map>().unwrap(),
-            Map(vec![(98..100, 50..52), (50..98, 52..100)])
+            Map {
+                from: "seed".to_string(),
+                to: "soil".to_string(),
+                ranges: vec![(98..100, 50..52), (50..98, 52..100)]
+            }
         );
     }
 
@@ -69,7 +92,7 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        assert_eq!(process(input)?, "35");
+        assert_eq!(process(input)?, 35.into());
         Ok(())
     }
 }
@@ -1,39 +1,46 @@
 use std::ops::Range;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use tracing::{debug, instrument};
 
-use crate::map::Map;
+use crate::map::Almanac;
+use crate::Day05;
 
-pub fn process(input: &str) -> Result<String> {
-    let (seeds, maps) = input.split_once("\n\n").unwrap();
-    let seeds: Vec<Range<u64>> = seeds
-        .strip_prefix("seeds: ")
-        .expect("seeds to be valid")
-        .split_whitespace()
-        .map(|s| s.parse::<u64>().expect("seed to be a number"))
+#[instrument(name = "solve", skip_all)]
+pub fn solve(almanac: &Almanac) -> Result<Answer> {
+    let seeds: Vec<Range<u64>> = almanac
+        .seeds
+        .iter()
         .tuples()
-        .map(|(seed, range)| seed..seed + range)
-        .collect();
-
-    let maps: Vec<Map> = maps
-        .split("\n\n")
-        .map(|map| map.parse().expect("to ve a valid map"))
-        .collect();
+        .map(|(&seed, &range)| {
+            let end = seed.checked_add(range);
+            end.map(|end| seed..end)
+                .ok_or(anyhow!("seed range {seed} {range} overflows"))
+        })
+        .collect::<Result<_>>()?;
+    let count: u64 = seeds.iter().map(|r| r.end - r.start).sum();
+    debug!(ranges = seeds.len(), seeds = count, "mapping every seed");
 
     Ok(seeds
         .into_par_iter()
         .flat_map(|r| r.clone())
-        .map(|seed| maps.iter().fold(seed, |source, map| map.get(source)))
+        .map(|seed| almanac.location(seed))
         .min()
-        .expect("to have a min")
-        .to_string())
+        .ok_or(anyhow!("every seed range is empty"))?
+        .into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day05::parse(input)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::map::Map;
 
     #[test]
     fn test_parse_seed_to_soil() {
@@ -42,7 +49,11 @@ mod tests {
 52 50 48";
         assert_eq!(
             input.parse::<Map>().unwrap(),
-            Map(vec![(98..100, 50..52), (50..98, 52..100)])
+            Map {
+                from: "seed".to_string(),
+                to: "soil".to_string(),
+                ranges: vec![(98..100, 50..52), (50..98, 52..100)]
+            }
         );
     }
 
@@ -81,7 +92,20 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        assert_eq!(process(input)?, "46");
+        assert_eq!(process(input)?, 46.into());
         Ok(())
     }
+
+    #[test]
+    fn test_process_error() {
+        let input = "seeds: 18446744073709551615 2\n\nseed-to-soil map:\n50 98 2";
+        let error = process(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "seed range 18446744073709551615 2 overflows"
+        );
+        let input = "seeds: 79 0\n\nseed-to-soil map:\n50 98 2";
+        let error = process(input).unwrap_err();
+        assert_eq!(error.to_string(), "every seed range is empty");
+    }
 }
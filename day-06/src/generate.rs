@@ -0,0 +1,96 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// Longest joined race, keeping its record distance within a `u64`
+const MAX_TIME: u64 = 1_000_000_000;
+
+/// Pick a record for a race, often on the edges of the quadratic: right below the best
+/// distance, reached exactly by some hold, or out of reach
+fn record<R: Rng + ?Sized>(rng: &mut R, time: u64) -> u64 {
+    let best = (time / 2) * (time - time / 2);
+    let hold = rng.gen_range(0..=time / 2);
+    match rng.gen_range(0..4) {
+        0 => best - 1,
+        1 => hold * (time - hold),
+        2 => best + rng.gen_range(0..=time),
+        _ => rng.gen_range(0..best),
+    }
+}
+
+/// Generate up to 4 races that join into one lasting `size / 2` to `size` milliseconds
+///
+/// Some races, the joined one included, may not be winnable at all.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = (size as u64).clamp(2, MAX_TIME);
+    let times = loop {
+        let time = rng.gen_range(size.div_ceil(2).max(2)..=size).to_string();
+        // Only cut before a nonzero digit, so that the races join back into the time
+        let mut cuts: Vec<_> = (1..time.len()).filter(|&i| &time[i..=i] != "0").collect();
+        cuts.shuffle(rng);
+        cuts.truncate(rng.gen_range(0..=cuts.len().min(3)));
+        cuts.sort();
+        let bounds: Vec<_> = [0].into_iter().chain(cuts).chain([time.len()]).collect();
+        let times: Vec<u64> = bounds
+            .windows(2)
+            .map(|w| time[w[0]..w[1]].parse().expect("to be digits"))
+            .collect();
+        if times.iter().all(|&t| t >= 2) {
+            break times;
+        }
+    };
+    // Records short enough for the joined record to fit in a `u64`
+    let distances: Vec<u64> = times
+        .iter()
+        .map(|&t| {
+            let record = record(rng, t);
+            let digits = 10_u64.pow(2 * t.ilog10());
+            match times.len() {
+                1 => record,
+                _ if record < digits => record,
+                _ => rng.gen_range(0..digits),
+            }
+        })
+        .collect();
+    let line = |values: &[u64]| {
+        let values: Vec<_> = values.iter().map(|v| format!("{v:>4}")).collect();
+        values.join(" ")
+    };
+    format!(
+        "Time:     {}\nDistance: {}\n",
+        line(&times),
+        line(&distances)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{oracle, part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        for seed in 0..50 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 100_000);
+            assert_eq!(input, generate(&mut StdRng::seed_from_u64(seed), 100_000));
+            assert_eq!(input.lines().count(), 2);
+            part1::process(&input)?;
+            part2::process(&input)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_edges() -> Result<()> {
+        let mut unwinnable = 0;
+        for seed in 0..50 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 1000);
+            assert_eq!(part1::process(&input)?, oracle::part1(&input)?);
+            assert_eq!(part2::process(&input)?, oracle::part2(&input)?);
+            unwinnable += (part1::process(&input)? == 0.into()) as usize;
+        }
+        assert!(unwinnable > 0);
+        Ok(())
+    }
+}
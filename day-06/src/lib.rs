@@ -1,4 +1,100 @@
+use anyhow::Result;
+use aoc_common::{
+    parse::{literal, numbers, parse, IResult},
+    Answer, Solver,
+};
+use nom::{
+    character::complete::{line_ending, space1},
+    combinator::cut,
+    error::{context, ErrorKind, VerboseError, VerboseErrorKind},
+    sequence::{preceded, terminated},
+};
+use tracing::instrument;
+
+use crate::race::Race;
+
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
-mod race;
+pub mod race;
+
+/// Parse the numbers following the given label
+fn values<'a>(
+    name: &'static str,
+    label: &'static str,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<u64>> {
+    context(
+        name,
+        preceded(literal(label), cut(preceded(space1, numbers))),
+    )
+}
+
+/// Parse the `Time:` and `Distance:` lines into races, with as many distances as times
+fn races(input: &str) -> IResult<'_, Vec<Race>> {
+    let (line, times) = terminated(values("times", "Time:"), line_ending)(input)?;
+    let (rest, distances) = values("distances", "Distance:")(line)?;
+    // Trailing input on the line is reported by `parse` instead
+    if rest.trim().is_empty() && distances.len() != times.len() {
+        return Err(nom::Err::Failure(VerboseError {
+            errors: vec![
+                (line, VerboseErrorKind::Nom(ErrorKind::Count)),
+                (line, VerboseErrorKind::Context("distances")),
+            ],
+        }));
+    }
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race::new(time, distance))
+        .collect();
+    Ok((rest, races))
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = Vec<Race>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input, races)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day06::parse("Time:      7  15   30\nDistance:  9  x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected trailing input at line 2, column 15"
+        );
+
+        let error = Day06::parse("Time:      7  15   30\nDistance:").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid distances: expected a space at line 2, column 10"
+        );
 
+        for input in ["Time: 7 15\nDistance: 9", "Time: 7\nDistance: 9 40"] {
+            let error = Day06::parse(input).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "invalid distances: wrong number of values at line 2, column 1"
+            );
+        }
+    }
+}
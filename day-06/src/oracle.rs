@@ -0,0 +1,51 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Values of the line starting with the label, as written in the input
+fn values<'a>(input: &'a str, label: &str) -> Result<Vec<&'a str>> {
+    let line = input
+        .lines()
+        .find_map(|line| line.strip_prefix(label))
+        .ok_or(anyhow!("no `{label}` line"))?;
+    Ok(line.split_whitespace().collect())
+}
+
+/// Try holding the button for every possible time, counting the ones beating the record
+fn ways(time: u64, record: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > record)
+        .count() as u64
+}
+
+/// Multiply the ways to win each race
+pub fn part1(input: &str) -> Result<Answer> {
+    let times = values(input, "Time:")?;
+    let records = values(input, "Distance:")?;
+    let mut product = 1;
+    for (time, record) in times.into_iter().zip(records) {
+        product *= ways(time.parse()?, record.parse()?);
+    }
+    Ok(product.into())
+}
+
+/// Count the ways to win the race written with the spaces removed
+pub fn part2(input: &str) -> Result<Answer> {
+    let time = values(input, "Time:")?.concat().parse()?;
+    let record = values(input, "Distance:")?.concat().parse()?;
+    Ok(ways(time, record).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        assert_eq!(part1(input)?, 288.into());
+        assert_eq!(part2(input)?, 71503.into());
+        Ok(())
+    }
+}
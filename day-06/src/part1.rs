@@ -1,36 +1,22 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::{debug, instrument};
 
 use crate::race::Race;
+use crate::Day06;
 
-fn parse_input(input: &str) -> Vec<Race> {
-    let mut lines = input.lines();
-    let times = lines
-        .next()
-        .expect("to have the times")
-        .strip_prefix("Time:")
-        .expect("to start with `Time:`")
-        .split_whitespace()
-        .map(|n| n.parse::<u64>().unwrap());
-    let distances = lines
-        .next()
-        .expect("to have the times")
-        .strip_prefix("Distance:")
-        .expect("to start with `Distance:`")
-        .split_whitespace()
-        .map(|n| n.parse::<u64>().unwrap());
-    times
-        .zip(distances)
-        .map(|(time, distance)| Race::new(time, distance))
-        .collect()
+#[instrument(name = "solve", skip_all)]
+pub fn solve(races: &[Race]) -> Result<Answer> {
+    let ways = races.iter().map(|race| {
+        let ways = race.get_n_ways();
+        debug!(?race, ways, "race");
+        ways
+    });
+    Ok(ways.product::<u64>().into())
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let races = parse_input(input);
-    Ok(races
-        .iter()
-        .map(|r| r.get_n_ways())
-        .product::<u64>()
-        .to_string())
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day06::parse(input)?)
 }
 
 #[cfg(test)]
@@ -41,7 +27,7 @@ mod tests {
     fn test_process() -> Result<()> {
         let input = r"Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!(process(input).unwrap(), "288");
+        assert_eq!(process(input).unwrap(), 288.into());
         Ok(())
     }
 }
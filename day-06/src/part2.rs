@@ -1,35 +1,19 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::{debug, instrument};
 
 use crate::race::Race;
+use crate::Day06;
 
-fn parse_input(input: &str) -> Race {
-    let mut lines = input.lines();
-    let times = lines
-        .next()
-        .expect("to have the times")
-        .strip_prefix("Time:")
-        .expect("to start with `Time:`")
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .collect::<String>()
-        .parse::<u64>()
-        .unwrap();
-    let distances = lines
-        .next()
-        .expect("to have the times")
-        .strip_prefix("Distance:")
-        .expect("to start with `Distance:`")
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .collect::<String>()
-        .parse::<u64>()
-        .unwrap();
-    Race::new(times, distances)
+#[instrument(name = "solve", skip_all)]
+pub fn solve(races: &[Race]) -> Result<Answer> {
+    let race = Race::join(races)?;
+    debug!(?race, "joined");
+    Ok(race.get_n_ways().into())
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let race = parse_input(input);
-    Ok(race.get_n_ways().to_string())
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day06::parse(input)?)
 }
 
 #[cfg(test)]
@@ -40,7 +24,7 @@ mod tests {
     fn test_process() -> Result<()> {
         let input = r"Time:      7  15   30
 Distance:  9  40  200";
-        assert_eq!(process(input).unwrap(), "71503");
+        assert_eq!(process(input).unwrap(), 71503.into());
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use anyhow::{anyhow, Result};
+
 #[derive(Debug)]
 pub struct Race {
     time: u64,
@@ -9,13 +11,82 @@ impl Race {
         Self { time, distance }
     }
 
+    /// Join the races into a single one by concatenating their digits, failing when
+    /// the joined numbers do not fit in a `u64`
+    pub fn join(races: &[Race]) -> Result<Self> {
+        let concat = |a: u64, b: u64| {
+            10_u64
+                .checked_pow(b.checked_ilog10().unwrap_or(0) + 1)
+                .and_then(|shift| a.checked_mul(shift))
+                .and_then(|a| a.checked_add(b))
+                .ok_or(anyhow!(
+                    "joined race is too long, {a} followed by {b} overflows"
+                ))
+        };
+        races.iter().try_fold(Race::new(0, 0), |race, r| {
+            Ok(Race::new(
+                concat(race.time, r.time)?,
+                concat(race.distance, r.distance)?,
+            ))
+        })
+    }
+
+    /// Whether holding the button for `hold` milliseconds beats the record
+    fn beats(&self, hold: u64) -> bool {
+        hold as u128 * (self.time - hold) as u128 > self.distance as u128
+    }
+
     /// Return the number of ways to win the race
+    ///
+    /// The roots of the quadratic only give a first guess of the shortest winning hold,
+    /// which is then adjusted since floats are not exact for large races.
     pub fn get_n_ways(&self) -> u64 {
+        // Holding half the time goes the farthest
+        if !self.beats(self.time / 2) {
+            return 0;
+        }
         let t = self.time as f64;
         let r = self.distance as f64;
-        let disc = (t.powf(2.0) - (4.0 * r)).sqrt();
-        let min = (-t + disc) / -2.0;
-        let max = (-t - disc) / -2.0;
-        max.ceil() as u64 - min.floor() as u64 - 1
+        let disc = (t.powf(2.0) - (4.0 * r)).max(0.0).sqrt();
+        let mut min = (((t - disc) / 2.0).floor() as u64).min(self.time / 2);
+        while min > 0 && self.beats(min - 1) {
+            min -= 1;
+        }
+        while !self.beats(min) {
+            min += 1;
+        }
+        // The winning holds are symmetric around half the time
+        self.time - 2 * min + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join() -> Result<()> {
+        let race = Race::join(&[Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)])?;
+        assert_eq!((race.time, race.distance), (71530, 940200));
+        let races = [Race::new(7, 9), Race::new(15, 40), Race::new(30, u64::MAX)];
+        assert!(Race::join(&races).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_n_ways() {
+        assert_eq!(Race::new(7, 9).get_n_ways(), 4);
+        assert_eq!(Race::new(7, 0).get_n_ways(), 6);
+        assert_eq!(Race::new(30, 200).get_n_ways(), 9);
+        assert_eq!(Race::new(30, 224).get_n_ways(), 1);
+        assert_eq!(Race::new(30, 225).get_n_ways(), 0);
+        assert_eq!(Race::new(30, 1000).get_n_ways(), 0);
+        assert_eq!(Race::new(0, 0).get_n_ways(), 0);
+        let (time, hold) = (999_999_999, 123_456_789);
+        assert_eq!(
+            Race::new(time, hold * (time - hold)).get_n_ways(),
+            time - 2 * hold - 1
+        );
+        assert_eq!(Race::new(time, 249_999_999_499_999_999).get_n_ways(), 2);
     }
 }
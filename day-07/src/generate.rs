@@ -0,0 +1,34 @@
+use rand::{seq::SliceRandom, Rng};
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generate `size` hands of five cards with bids up to 1000
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let hand: String = (0..5)
+                .map(|_| *CARDS.choose(rng).unwrap() as char)
+                .collect();
+            format!("{hand} {}\n", rng.gen_range(1..=1000))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 50));
+        assert_eq!(input.lines().count(), 50);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
@@ -1,2 +1,89 @@
+use anyhow::Result;
+use aoc_common::{
+    parse::{lines, number, parse, IResult},
+    Answer, Solver,
+};
+use nom::{
+    character::complete::{one_of, space1},
+    combinator::{cut, recognize},
+    error::context,
+    multi::count,
+    sequence::{pair, preceded},
+};
+use tracing::instrument;
+
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+/// Cards from weakest to strongest
+const CARDS: &str = "23456789TJQKA";
+
+/// Parse a `<cards> <bid>` line, a hand having exactly five cards
+fn hand(input: &str) -> IResult<'_, (&str, u32)> {
+    let card = || context("card", one_of(CARDS));
+    let cards = recognize(pair(card(), cut(count(card(), 4))));
+    context("hand", pair(cards, cut(preceded(space1, number))))(input)
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+    /// The cards of each hand along with its bid
+    type Input<'a> = Vec<(&'a str, u32)>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input, lines(hand)).map_err(|e| {
+            e.into_diagnostic(input)
+                .with_help("hands look like `32T3K 765`, with five cards out of `23456789TJQKA`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let hands = Day07::parse("32T3K 765\nT55J5 684\n")?;
+        assert_eq!(hands, vec![("32T3K", 765), ("T55J5", 684)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day07::parse("XXXXX 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid card: unexpected character at line 1, column 1"
+        );
+        let error = Day07::parse("32T3K 765\n32T3 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid card: unexpected character at line 2, column 5"
+        );
+        let error = Day07::parse("32T3KK 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid hand: expected a space at line 1, column 6"
+        );
+        let error = Day07::parse("32T3K765").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid hand: expected a space at line 1, column 6"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
+
+use crate::Day07;
+
+/// Cards from weakest to strongest
+const CARDS: &str = "23456789TJQKA";
+/// Cards from weakest to strongest when `J` is a joker
+const JOKER_CARDS: &str = "J23456789TQKA";
+
+/// How many times each card shows, most frequent first, so that stronger types
+/// compare greater: `[5]` beats `[4, 1]` which beats `[3, 2]` and so on
+fn strength(cards: &str) -> Vec<usize> {
+    let mut counts: Vec<_> = CARDS.chars().map(|c| cards.matches(c).count()).collect();
+    counts.retain(|&count| count > 0);
+    counts.sort_by(|a, b| b.cmp(a));
+    counts
+}
+
+/// Strongest type reachable by turning each joker into any other card
+fn joker_strength(cards: &str) -> Vec<usize> {
+    match cards.find('J') {
+        None => strength(cards),
+        Some(i) => CARDS
+            .chars()
+            .filter(|&c| c != 'J')
+            .map(|c| joker_strength(&format!("{}{c}{}", &cards[..i], &cards[i + 1..])))
+            .max()
+            .expect("to have cards"),
+    }
+}
+
+/// Rank the hands by their type then by their cards in `order`, summing bids times ranks
+fn winnings(input: &str, order: &str, strength: fn(&str) -> Vec<usize>) -> Result<Answer> {
+    let mut hands = vec![];
+    for (cards, bid) in Day07::parse(input)? {
+        let values = cards
+            .chars()
+            .map(|c| order.find(c).ok_or(anyhow!("invalid card {c} in {cards}")))
+            .collect::<Result<Vec<_>>>()?;
+        hands.push(((strength(cards), values), bid));
+    }
+    hands.sort();
+    let total: u32 = (1..).zip(&hands).map(|(rank, (_, bid))| rank * bid).sum();
+    Ok(total.into())
+}
+
+/// Winnings of the hands ranked by counting their cards
+pub fn part1(input: &str) -> Result<Answer> {
+    winnings(input, CARDS, strength)
+}
+
+/// Winnings of the hands ranked by trying every card for each joker
+pub fn part2(input: &str) -> Result<Answer> {
+    winnings(input, JOKER_CARDS, joker_strength)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = r"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        assert_eq!(part1(input)?, 6440.into());
+        assert_eq!(part2(input)?, 5905.into());
+        Ok(())
+    }
+}
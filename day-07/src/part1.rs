@@ -1,7 +1,11 @@
 use std::cmp::Ordering;
 
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
 use itertools::Itertools;
+use tracing::instrument;
+
+use crate::Day07;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
@@ -70,6 +74,10 @@ impl Hand {
             hand_type: card_type,
         }
     }
+
+    pub fn hand_type(&self) -> &HandType {
+        &self.hand_type
+    }
 }
 
 impl PartialOrd for Hand {
@@ -95,17 +103,21 @@ impl Ord for Hand {
     }
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let hands: u32 = input
-        .lines()
-        .flat_map(|line| line.split_once(' '))
-        .map(|(hand, c)| (Hand::new(hand), c.parse::<u32>().unwrap()))
+#[instrument(name = "solve", skip_all)]
+pub fn solve(hands: &[(&str, u32)]) -> Result<Answer> {
+    let hands: u32 = hands
+        .iter()
+        .map(|(hand, bid)| (Hand::new(hand), *bid))
         .sorted_by(|a, b| a.0.cmp(&b.0))
         .enumerate()
         .map(|(idx, (_, val))| (idx as u32 + 1) * val)
         .sum();
 
-    Ok(hands.to_string())
+    Ok(hands.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day07::parse(input)?)
 }
 
 #[cfg(test)]
@@ -152,7 +164,7 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        assert_eq!(process(input)?, "6440");
+        assert_eq!(process(input)?, 6440.into());
         Ok(())
     }
 }
@@ -1,29 +1,79 @@
-#[derive(Debug)]
-pub enum Dir {
-    Left,
-    Right,
+use std::collections::HashMap;
+
+use aoc_common::parse::{lines, literal, parse, IResult, ParseError};
+use aoc_grid::Turn;
+use nom::{
+    branch::alt,
+    character::complete::{alphanumeric1, char, line_ending},
+    combinator::{cut, map, value},
+    error::context,
+    multi::{count, many1},
+    sequence::{delimited, separated_pair},
+};
+
+/// Parse a `AAA = (BBB, CCC)` node
+pub fn node(input: &str) -> IResult<'_, (&str, (&str, &str))> {
+    context(
+        "node",
+        separated_pair(
+            alphanumeric1,
+            cut(literal(" = ")),
+            cut(delimited(
+                char('('),
+                separated_pair(alphanumeric1, literal(", "), alphanumeric1),
+                char(')'),
+            )),
+        ),
+    )(input)
 }
 
-impl TryFrom<char> for Dir {
-    type Error = ();
+/// Parse the `L` and `R` directions
+fn directions(input: &str) -> IResult<'_, Vec<Turn>> {
+    context(
+        "directions",
+        many1(alt((
+            value(Turn::Left, char('L')),
+            value(Turn::Right, char('R')),
+        ))),
+    )(input)
+}
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            'L' => Ok(Dir::Left),
-            'R' => Ok(Dir::Right),
-            _ => Err(()),
-        }
+#[derive(Debug)]
+pub struct Network<'a> {
+    pub directions: Vec<Turn>,
+    pub nodes: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+impl<'a> Network<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        parse(
+            input,
+            map(
+                separated_pair(directions, count(line_ending, 2), lines(node)),
+                |(directions, nodes)| Network {
+                    directions,
+                    nodes: nodes.into_iter().collect(),
+                },
+            ),
+        )
     }
 }
 
-pub fn parse_line(line: &str) -> (&str, (&str, &str)) {
-    let (index, rest) = line.split_once(" = ").expect("to have an =");
-    let rest = rest
-        .strip_prefix('(')
-        .expect("to start with (")
-        .strip_suffix(')')
-        .expect("to end with )")
-        .split_once(", ")
-        .expect("to have a ,");
-    (index, rest)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_node() {
+        assert_eq!(parse("11A = (11B, XXX)", node), Ok(("11A", ("11B", "XXX"))));
+    }
+
+    #[test]
+    fn test_parse_network_error() {
+        let error = Network::new("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA CCC)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid node: expected `, ` at line 4, column 11"
+        );
+    }
 }
@@ -0,0 +1,155 @@
+use rand::{seq::SliceRandom, Rng};
+
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Most `A` nodes of a network, the first one being `AAA`
+const GHOSTS: usize = 6;
+
+/// Factors of the cycle lengths, small so that the ghosts meet soon enough to be
+/// walked step by step
+const PRIMES: [usize; 4] = [2, 3, 5, 7];
+
+/// Every pair of characters, starting the names of the nodes
+fn prefixes() -> impl Iterator<Item = String> {
+    ALPHABET.iter().flat_map(|&a| {
+        ALPHABET
+            .iter()
+            .map(move |&b| format!("{}{}", a as char, b as char))
+    })
+}
+
+/// Every name of three characters which is neither an `A` nor a `Z` node
+fn inner_names<R: Rng + ?Sized>(rng: &mut R) -> Vec<String> {
+    let mut names: Vec<_> = prefixes()
+        .flat_map(|prefix| {
+            ALPHABET
+                .iter()
+                .map(move |&c| format!("{prefix}{}", c as char))
+        })
+        .filter(|name| !name.ends_with(['A', 'Z']))
+        .collect();
+    names.shuffle(rng);
+    names
+}
+
+/// Generate a network of about `size` nodes, or a few dozen at least, where every ghost reaches a `Z` node
+///
+/// As in the puzzle, each ghost ends up walking a cycle through its `Z` node, reached
+/// after as many steps as the cycle is long, so that the ghosts meet on `Z` nodes after
+/// the least common multiple of the cycles. The length of a cycle is a prime times the
+/// length of the directions. The first ghost walks from `AAA` to `ZZZ`.
+///
+/// Unlike the puzzle, a ghost may walk a few nodes before entering its cycle, and one
+/// ghost may walk a cycle whose length is not a multiple of the directions, every node
+/// of it leading to the next one both ways.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut names = inner_names(rng);
+    let ghosts = rng.gen_range(2..=GHOSTS);
+    let primes: Vec<usize> = (0..ghosts).map(|_| *PRIMES.choose(rng).unwrap()).collect();
+    // The way into a cycle takes up to one more length, and an unaligned cycle too
+    let total = primes.iter().sum::<usize>() + ghosts;
+    // Keep enough names for every node of the cycles, and at least two directions for
+    // a cycle not to be a multiple of them
+    let length = (size.saturating_sub(ghosts) / total).clamp(2, names.len() / (total + ghosts));
+    let directions: Vec<char> = (0..length)
+        .map(|_| *['L', 'R'].choose(rng).unwrap())
+        .collect();
+    let unaligned = rng.gen_range(0..ghosts);
+
+    let mut prefixes: Vec<_> = prefixes()
+        .filter(|prefix| prefix != "AA" && prefix != "ZZ")
+        .collect();
+    prefixes.shuffle(rng);
+    prefixes.insert(0, "AA".to_string());
+
+    let mut nodes = vec![];
+    for (ghost, (prime, prefix)) in primes.iter().zip(&prefixes).enumerate() {
+        let mut cycle = prime * length;
+        let both_ways = ghost == unaligned;
+        if both_ways {
+            cycle += rng.gen_range(1..length);
+        }
+        // Shorter than the cycle, so that the `Z` node is first reached after a cycle
+        let tail = rng.gen_range(0..=length);
+
+        // Nodes reached after each step, the way in then the cycle, whose `Z` node is
+        // reached after `cycle` steps
+        let mut path: Vec<_> = names.drain(..tail + cycle - 1).collect();
+        path.insert(
+            cycle - 1,
+            match prefix.as_str() {
+                "AA" => "ZZZ".to_string(),
+                _ => format!("{prefix}Z"),
+            },
+        );
+        let reached = |step: usize| {
+            if step <= tail {
+                &path[step - 1]
+            } else {
+                &path[tail + (step - tail - 1) % cycle]
+            }
+        };
+        // The node reached after `step` moves leads to the one reached after the next
+        let mut node = |name: &str, step: usize| {
+            let next = reached(step + 1).clone();
+            let other = if both_ways {
+                next.clone()
+            } else {
+                path.choose(rng).unwrap().clone()
+            };
+            let (left, right) = match directions[step % length] {
+                'L' => (next, other),
+                _ => (other, next),
+            };
+            nodes.push(format!("{name} = ({left}, {right})"));
+        };
+        node(&format!("{prefix}A"), 0);
+        for step in 1..=tail + cycle {
+            node(&reached(step).clone(), step);
+        }
+    }
+    nodes.shuffle(rng);
+
+    let directions: String = directions.into_iter().collect();
+    format!("{directions}\n\n{}\n", nodes.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{oracle, part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 2000);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 2000));
+        let nodes = input.lines().count() - 2;
+        assert!((1000..=2000).contains(&nodes), "{nodes} nodes");
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 0);
+        assert_eq!(input.lines().next().map(str::len), Some(2));
+        assert!((2..=GHOSTS).contains(&input.matches("A =").count()));
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_ghosts() -> Result<()> {
+        for seed in 0..20 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            assert!(input.matches("A =").count() >= 2);
+            assert_eq!(part2::process(&input)?, oracle::part2(&input)?);
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,35 @@
-mod dir;
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
+
+use crate::dir::Network;
+
+pub mod dir;
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+    type Input<'a> = Network<'a>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Network::new(input).map_err(|e| {
+            e.into_diagnostic(input)
+                .with_help("the directions line is followed by nodes such as `AAA = (BBB, CCC)`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,74 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
+use aoc_grid::Turn;
+
+use crate::dir::Network;
+use crate::Day08;
+
+/// Node reached from the node by taking the `step`th direction
+fn next<'a>(network: &Network<'a>, node: &str, step: usize) -> Result<&'a str> {
+    let (left, right) = network
+        .nodes
+        .get(node)
+        .ok_or(anyhow!("no node named {node}"))?;
+    Ok(match network.directions[step % network.directions.len()] {
+        Turn::Left => left,
+        Turn::Right => right,
+    })
+}
+
+/// Walk from `AAA` until reaching `ZZZ`
+pub fn part1(input: &str) -> Result<Answer> {
+    let network = Day08::parse(input)?;
+    let mut node = "AAA";
+    let mut steps = 0;
+    while node != "ZZZ" {
+        node = next(&network, node, steps)?;
+        steps += 1;
+    }
+    Ok(steps.into())
+}
+
+/// Move every ghost at once, one step at a time, until they all stand on a `Z` node
+pub fn part2(input: &str) -> Result<Answer> {
+    let network = Day08::parse(input)?;
+    let mut ghosts: Vec<&str> = network
+        .nodes
+        .keys()
+        .copied()
+        .filter(|node| node.ends_with('A'))
+        .collect();
+    let mut steps = 0;
+    while !ghosts.iter().all(|node| node.ends_with('Z')) {
+        for ghost in &mut ghosts {
+            *ghost = next(&network, ghost, steps)?;
+        }
+        steps += 1;
+    }
+    Ok(steps.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        assert_eq!(part1(input)?, 6.into());
+        let input = r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(part2(input)?, 6.into());
+        Ok(())
+    }
+}
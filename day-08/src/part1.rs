@@ -1,34 +1,33 @@
-use std::collections::HashMap;
-
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use aoc_grid::Turn;
+use tracing::{debug, instrument};
 
-use crate::dir::{parse_line, Dir};
-
-pub fn process(input: &str) -> Result<String> {
-    let mut lines = input.lines();
-    let mut directions = lines
-        .next()
-        .expect("to have the directions")
-        .chars()
-        .filter_map(|c| Dir::try_from(c).ok())
-        .cycle();
-
-    let map: HashMap<&str, (&str, &str)> = lines.skip(1).map(parse_line).collect();
+use crate::dir::Network;
+use crate::Day08;
 
+#[instrument(name = "solve", skip_all)]
+pub fn solve(network: &Network) -> Result<Answer> {
+    let mut directions = network.directions.iter().cycle();
     let mut cursor = "AAA";
     let target = "ZZZ";
     let mut steps = 0;
     while cursor != target {
-        let values = map.get(cursor).expect("to have an entry");
+        let values = network.nodes.get(cursor).expect("to have an entry");
         cursor = match directions.next() {
-            Some(Dir::Left) => values.0,
-            Some(Dir::Right) => values.1,
+            Some(Turn::Left) => values.0,
+            Some(Turn::Right) => values.1,
             None => unreachable!(),
         };
         steps += 1;
     }
+    debug!(steps, directions = network.directions.len(), "reached ZZZ");
+
+    Ok(steps.into())
+}
 
-    Ok(steps.to_string())
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day08::parse(input)?)
 }
 
 #[cfg(test)]
@@ -47,7 +46,7 @@ DDD = (DDD, DDD)
 EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)",
-        "2"
+        2
     )]
     #[case(
         "LLR
@@ -55,10 +54,10 @@ ZZZ = (ZZZ, ZZZ)",
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)",
-        "6"
+        6
     )]
-    fn test_process(#[case] input: &str, #[case] expected: &str) -> Result<()> {
-        assert_eq!(process(input)?, expected);
+    fn test_process(#[case] input: &str, #[case] expected: u64) -> Result<()> {
+        assert_eq!(process(input)?, expected.into());
         Ok(())
     }
 }
@@ -1,44 +1,40 @@
-use std::{collections::HashMap, usize};
-
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use aoc_grid::Turn;
 use num::Integer;
-
-use crate::dir::{parse_line, Dir};
-
-pub fn process(input: &str) -> Result<String> {
-    let mut lines = input.lines();
-    let directions: Vec<Dir> = lines
-        .next()
-        .expect("to have the directions")
-        .chars()
-        .filter_map(|c| Dir::try_from(c).ok())
-        .collect();
-
-    let nodes = lines.skip(1).map(parse_line);
-    let map: HashMap<&str, (&str, &str)> = nodes.clone().collect();
-    let nodes: Vec<&str> = nodes
-        .filter_map(|(index, _)| index.ends_with('A').then_some(index))
-        .collect();
-
-    let results: Vec<_> = nodes
-        .into_iter()
-        .map(|node| {
-            let mut dir = directions.iter().cycle();
+use tracing::{debug, instrument};
+
+use crate::dir::Network;
+use crate::Day08;
+
+#[instrument(name = "solve", skip_all)]
+pub fn solve(network: &Network) -> Result<Answer> {
+    let results: Vec<_> = network
+        .nodes
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|&node| {
+            let mut dir = network.directions.iter().cycle();
             let mut cursor = node;
             let mut steps: usize = 0;
             while !cursor.ends_with('Z') {
-                let values = map.get(cursor).expect("to have an entry");
+                let values = network.nodes.get(cursor).expect("to have an entry");
                 steps += 1;
                 cursor = match dir.next().unwrap() {
-                    Dir::Left => values.0,
-                    Dir::Right => values.1,
+                    Turn::Left => values.0,
+                    Turn::Right => values.1,
                 };
             }
+            debug!(start = node, end = cursor, steps, "cycle");
             steps
         })
         .collect();
 
-    Ok(results.iter().fold(1, |res, x| res.lcm(x)).to_string())
+    Ok(results.iter().fold(1, |res, x| res.lcm(x)).into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day08::parse(input)?)
 }
 
 #[cfg(test)]
@@ -58,10 +54,10 @@ mod tests {
 22C = (22Z, 22Z)
 22Z = (22B, 22B)
 XXX = (XXX, XXX)",
-        "6"
+        6
     )]
-    fn test_process(#[case] input: &str, #[case] expected: &str) -> Result<()> {
-        assert_eq!(process(input)?, expected);
+    fn test_process(#[case] input: &str, #[case] expected: u64) -> Result<()> {
+        assert_eq!(process(input)?, expected.into());
         Ok(())
     }
 }
@@ -0,0 +1,52 @@
+use rand::Rng;
+
+/// Number of values of each sequence, as in the puzzle
+const LENGTH: i64 = 21;
+
+/// Generate `size` sequences, the values of polynomials of degree up to 6
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let degree = rng.gen_range(0..=6);
+            let coefficients: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-10..=10)).collect();
+            let values: Vec<_> = (0..LENGTH)
+                .map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c))
+                .map(|value| value.to_string())
+                .collect();
+            values.join(" ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 50));
+        assert_eq!(input.lines().count(), 50);
+        assert!(input.lines().all(|line| line.split(' ').count() == 21));
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_extrapolates() -> Result<()> {
+        // Every value of a polynomial is predicted from the previous ones
+        let input = generate(&mut StdRng::seed_from_u64(2), 1);
+        let values: Vec<i64> = input
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        let truncated: Vec<_> = values[..20].iter().map(i64::to_string).collect();
+        assert_eq!(part1::process(&truncated.join(" "))?, values[20].into());
+        Ok(())
+    }
+}
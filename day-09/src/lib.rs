@@ -1,2 +1,80 @@
+use anyhow::Result;
+use aoc_common::{
+    parse::{lines, parse, signed, IResult},
+    Answer, Solver,
+};
+use nom::{
+    character::complete::space1,
+    combinator::{cut, map},
+    error::context,
+    multi::many0,
+    sequence::{pair, preceded},
+};
+use tracing::instrument;
+
+pub mod generate;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+/// Parse a line of space separated values
+fn history(input: &str) -> IResult<'_, Vec<i64>> {
+    let values = pair(signed, many0(preceded(space1, cut(signed))));
+    context(
+        "history",
+        map(values, |(first, mut rest)| {
+            rest.insert(0, first);
+            rest
+        }),
+    )(input)
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Vec<i64>>;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input, lines(history)).map_err(|e| {
+            e.into_diagnostic(input)
+                .with_help("histories look like `0 3 6 9 12 15`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let histories = Day09::parse("0 3 6 9 12 15\n-1 -3 -6\n")?;
+        assert_eq!(histories, vec![vec![0, 3, 6, 9, 12, 15], vec![-1, -3, -6]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day09::parse("0 3 x 9 12 15").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid history: expected a number at line 1, column 5"
+        );
+        let error = Day09::parse("0 3 6\n1 3 6 1.5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected trailing input at line 2, column 8"
+        );
+    }
+}
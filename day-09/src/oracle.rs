@@ -0,0 +1,59 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+
+use crate::Day09;
+
+/// Rows of differences of the sequence, down to the first row of zeros
+fn table(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut rows = vec![values.to_vec()];
+    while rows.last().is_some_and(|row| row.iter().any(|&v| v != 0)) {
+        let row = rows.last().expect("to have a row");
+        let mut deltas = vec![];
+        for i in 1..row.len() {
+            deltas.push(row[i] - row[i - 1]);
+        }
+        rows.push(deltas);
+    }
+    rows
+}
+
+/// Add a value to the end of every row of the table, from the zeros up
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for values in Day09::parse(input)? {
+        let mut below = 0;
+        for row in table(&values).iter().rev() {
+            below += row.last().copied().unwrap_or(0);
+        }
+        total += below;
+    }
+    Ok(total.into())
+}
+
+/// Add a value to the start of every row of the table, from the zeros up
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for values in Day09::parse(input)? {
+        let mut below = 0;
+        for row in table(&values).iter().rev() {
+            below = row.first().copied().unwrap_or(0) - below;
+        }
+        total += below;
+    }
+    Ok(total.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        assert_eq!(part1(input)?, 114.into());
+        assert_eq!(part2(input)?, 2.into());
+        Ok(())
+    }
+}
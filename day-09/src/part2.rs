@@ -1,4 +1,8 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
+
+use crate::Day09;
 
 fn predict_prev(values: &[i64]) -> i64 {
     if values.iter().all(|v| *v == 0) {
@@ -14,17 +18,17 @@ fn predict_prev(values: &[i64]) -> i64 {
     first - predict_prev(&deltas)
 }
 
-pub fn process(input: &str) -> Result<String> {
-    let results = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .filter_map(|n| n.parse().ok())
-                .collect()
-        })
-        .map(|values: Vec<_>| predict_prev(&values))
+#[instrument(name = "solve", skip_all)]
+pub fn solve(sequences: &[Vec<i64>]) -> Result<Answer> {
+    let results = sequences
+        .iter()
+        .map(|values| predict_prev(values))
         .sum::<i64>();
-    Ok(results.to_string())
+    Ok(results.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day09::parse(input)?)
 }
 
 #[cfg(test)]
@@ -43,7 +47,7 @@ mod tests {
         let input = "0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45";
-        assert_eq!(process(input)?, "2");
+        assert_eq!(process(input)?, 2.into());
         Ok(())
     }
 }
@@ -0,0 +1,15 @@
+use aoc_common::bench::bench_solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use day_10::Day10;
+
+fn bench(c: &mut Criterion) {
+    bench_solver::<Day10>(
+        c,
+        include_str!("../example.txt"),
+        include_str!("../example2.txt"),
+    );
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_grid::{Dir, Grid, Point};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::map::{Map, Tile};
+
+/// Tiles off the loop, ground being as likely as every pipe together
+const JUNK: [Tile; 12] = [
+    Tile::NorthSouth,
+    Tile::EastWest,
+    Tile::NorthEast,
+    Tile::NorthWest,
+    Tile::SouthWest,
+    Tile::SouthEast,
+    Tile::Ground,
+    Tile::Ground,
+    Tile::Ground,
+    Tile::Ground,
+    Tile::Ground,
+    Tile::Ground,
+];
+
+/// Side of the blocks the loop goes around
+const BLOCK: i64 = 3;
+
+/// Grow a random tree over a `width` by `width` grid of blocks, returning the edges of
+/// each block of the tree
+fn tree<R: Rng + ?Sized>(rng: &mut R, width: usize) -> BTreeMap<Point, Vec<Dir>> {
+    let inside = |p: Point| (0..width as i64).contains(&p.x) && (0..width as i64).contains(&p.y);
+    let start = Point::new(
+        rng.gen_range(0..width) as i64,
+        rng.gen_range(0..width) as i64,
+    );
+    let target = rng.gen_range((width * width).div_ceil(2)..=width * width);
+
+    let mut tree = BTreeMap::from([(start, vec![])]);
+    let mut frontier: Vec<_> = Dir::CARDINALS.map(|dir| (start, dir)).into();
+    while tree.len() < target && !frontier.is_empty() {
+        let (block, dir) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        let next = block.step(dir);
+        if inside(next) && !tree.contains_key(&next) {
+            tree.get_mut(&block).unwrap().push(dir);
+            tree.insert(next, vec![dir.opposite()]);
+            frontier.extend(Dir::CARDINALS.map(|dir| (next, dir)));
+        }
+    }
+    tree
+}
+
+/// Return the pipe connecting the two directions
+fn pipe(dirs: [Dir; 2]) -> Tile {
+    let has = |dir| dirs.contains(&dir);
+    match (has(Dir::North), has(Dir::South), has(Dir::East)) {
+        (true, true, _) => Tile::NorthSouth,
+        (true, _, true) => Tile::NorthEast,
+        (true, _, false) => Tile::NorthWest,
+        (_, true, true) => Tile::SouthEast,
+        (_, true, false) => Tile::SouthWest,
+        _ => Tile::EastWest,
+    }
+}
+
+/// Return the pipes of the loop in a block, by position in the block
+///
+/// The loop follows the border of the block, except on the sides with an edge of the
+/// tree, where it goes into the next block instead.
+fn block_pipes(edges: &[Dir]) -> Vec<(Point, Tile)> {
+    let open = |dir| edges.contains(&dir);
+    let corners = [
+        (Point::new(0, 0), Dir::North, Dir::West),
+        (Point::new(2, 0), Dir::North, Dir::East),
+        (Point::new(0, 2), Dir::South, Dir::West),
+        (Point::new(2, 2), Dir::South, Dir::East),
+    ];
+    let sides = [
+        (Point::new(1, 0), Dir::North),
+        (Point::new(1, 2), Dir::South),
+        (Point::new(0, 1), Dir::West),
+        (Point::new(2, 1), Dir::East),
+    ];
+
+    let corners = corners.into_iter().map(|(point, vertical, horizontal)| {
+        let dirs = [
+            if open(vertical) {
+                vertical
+            } else {
+                horizontal.opposite()
+            },
+            if open(horizontal) {
+                horizontal
+            } else {
+                vertical.opposite()
+            },
+        ];
+        (point, pipe(dirs))
+    });
+    let sides = sides
+        .into_iter()
+        .filter(|(_, dir)| !open(*dir))
+        .map(|(point, dir)| (point, pipe([dir.turn_left(), dir.turn_right()])));
+    corners.chain(sides).collect()
+}
+
+/// Generate a `size` by `size` field of junk pipes with a single loop going through `S`
+///
+/// The loop goes around a random tree of 3 by 3 blocks, so it encloses the center of
+/// every block of the tree, along with the gap between each pair of connected blocks.
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(BLOCK as usize);
+    let mut tiles = Grid::from_fn(size, size, |_| *JUNK.choose(rng).unwrap());
+
+    let mut pipes = BTreeSet::new();
+    for (block, edges) in tree(rng, size / BLOCK as usize) {
+        for (point, tile) in block_pipes(&edges) {
+            let point = block * BLOCK + point;
+            tiles[point] = tile;
+            pipes.insert(point);
+        }
+    }
+
+    // Only the two pipes of the loop connect to the start
+    let start = *pipes.iter().collect::<Vec<_>>().choose(rng).unwrap();
+    tiles[*start] = Tile::Starting;
+    let neighbours: Vec<_> = tiles.neighbours4(*start).map(|(point, _)| point).collect();
+    for point in neighbours {
+        if !pipes.contains(&point) {
+            tiles[point] = Tile::Ground;
+        }
+    }
+    Map { tiles }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 40);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 40));
+        assert_eq!(input.lines().count(), 40);
+        assert_eq!(input.matches('S').count(), 1);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_single_block() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 3);
+        assert_eq!(part1::process(&input)?, 4.into());
+        assert_eq!(part2::process(&input)?, 1.into());
+        Ok(())
+    }
+}
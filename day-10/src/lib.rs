@@ -1,3 +1,35 @@
-mod map;
+use anyhow::Result;
+use aoc_common::{parse::ParseError, Answer, Solver};
+use tracing::instrument;
+
+use crate::map::Map;
+
+pub mod generate;
+pub mod map;
+pub mod oracle;
 pub mod part1;
 pub mod part2;
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Map;
+
+    #[instrument(name = "parse", skip_all)]
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse().map_err(|e: ParseError| {
+            e.into_diagnostic(input)
+                .with_help("tiles are one of `|-LJ7F.S`")
+                .into()
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -1,32 +1,10 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{fmt, str::FromStr};
 
 use anyhow::{anyhow, Error, Result};
+use aoc_common::parse::ParseError;
+use aoc_grid::{Dir, Grid, Point};
 
-type Point = (i64, i64);
-
-const DIRS: [Point; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-#[derive(Debug)]
-pub enum Dir {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl From<&Point> for Dir {
-    fn from(value: &Point) -> Self {
-        match value {
-            (1, 0) => Dir::East,
-            (-1, 0) => Dir::West,
-            (0, 1) => Dir::South,
-            (0, -1) => Dir::North,
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tile {
     NorthSouth,
     EastWest,
@@ -56,6 +34,27 @@ impl TryFrom<char> for Tile {
     }
 }
 
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            Tile::NorthSouth => '|',
+            Tile::EastWest => '-',
+            Tile::NorthEast => 'L',
+            Tile::NorthWest => 'J',
+            Tile::SouthWest => '7',
+            Tile::SouthEast => 'F',
+            Tile::Ground => '.',
+            Tile::Starting => 'S',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
 impl Tile {
     fn can_from(&self, dir: &Dir) -> bool {
         matches!(
@@ -84,51 +83,36 @@ impl Tile {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Map {
-    pub tiles: HashMap<Point, Tile>,
+    pub tiles: Grid<Tile>,
 }
 
 impl FromStr for Map {
-    type Err = Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tiles = s
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(move |(x, c)| c.try_into().map(|tile| ((x as i64, y as i64), tile)))
-            })
-            .collect();
+        Ok(Map { tiles: s.parse()? })
+    }
+}
 
-        match tiles {
-            Ok(tiles) => Ok(Map { tiles }),
-            Err(e) => Err(e),
-        }
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tiles)
     }
 }
 
 impl Map {
-    fn get(&self, point: &Point) -> Option<&Tile> {
-        self.tiles.get(point)
+    pub fn get(&self, point: &Point) -> Option<&Tile> {
+        self.tiles.get(*point)
     }
 
     pub fn get_starting(&self) -> Option<(Point, &Tile)> {
-        self.tiles
-            .iter()
-            .find(|(_, t)| *t == &Tile::Starting)
-            .map(|(p, t)| (*p, t))
+        self.tiles.iter().find(|(_, t)| *t == &Tile::Starting)
     }
 
     fn get_surrounds(&self, point: &Point) -> Vec<(Point, &Tile)> {
-        DIRS.iter()
-            .filter_map(|(x, y)| {
-                let delta = (point.0 + x, point.1 + y);
-                self.get(&delta).map(|tile| (delta, tile))
-            })
-            .collect()
+        self.tiles.neighbours4(*point).collect()
     }
 
     pub fn walk(&self, point: &Point, tile: &Tile, prev: &Point) -> (Point, &Tile) {
@@ -136,9 +120,7 @@ impl Map {
         *surrounds
             .iter()
             .filter(|(p, t)| {
-                let diff = (p.0 - point.0, p.1 - point.1);
-                let dir = Dir::from(&diff);
-                t.can_from(&dir) && tile.can_go(&dir)
+                Dir::try_from(*p - *point).is_ok_and(|dir| t.can_from(&dir) && tile.can_go(&dir))
             })
             .find(|(p, _)| p != prev)
             .expect("to have a successor")
@@ -147,6 +129,8 @@ impl Map {
 
 #[cfg(test)]
 mod tests {
+    use proptest::{collection::vec, prelude::*, sample::select};
+
     use super::*;
 
     #[test]
@@ -157,12 +141,12 @@ SJLL7
 |F--J
 LJ.LJ";
         let map: Map = input.parse()?;
-        assert_eq!(map.get(&(0, 0)), Some(&Tile::SouthWest));
-        assert_eq!(map.get(&(0, 1)), Some(&Tile::Ground));
-        assert_eq!(map.get(&(4, 4)), Some(&Tile::NorthWest));
-        assert_eq!(map.get(&(5, 4)), None);
-        assert_eq!(map.get(&(4, 5)), None);
-        assert_eq!(map.get(&(-1, -1)), None);
+        assert_eq!(map.get(&Point::new(0, 0)), Some(&Tile::SouthWest));
+        assert_eq!(map.get(&Point::new(0, 1)), Some(&Tile::Ground));
+        assert_eq!(map.get(&Point::new(4, 4)), Some(&Tile::NorthWest));
+        assert_eq!(map.get(&Point::new(5, 4)), None);
+        assert_eq!(map.get(&Point::new(4, 5)), None);
+        assert_eq!(map.get(&Point::new(-1, -1)), None);
         Ok(())
     }
 
@@ -175,8 +159,11 @@ SJLL7
 LJ.LJ";
         let map: Map = input.parse()?;
         assert_eq!(
-            map.get_surrounds(&(0, 0)),
-            [((1, 0), &Tile::EastWest), ((0, 1), &Tile::Ground)]
+            map.get_surrounds(&Point::new(0, 0)),
+            [
+                (Point::new(1, 0), &Tile::EastWest),
+                (Point::new(0, 1), &Tile::Ground)
+            ]
         );
         Ok(())
     }
@@ -189,7 +176,7 @@ SJLL7
 |F--J
 LJ.LJ";
         let map: Map = input.parse()?;
-        let a = map.get(&(0, 0)).unwrap();
+        let a = map.get(&Point::new(0, 0)).unwrap();
         assert!(a.can_go(&Dir::South));
         assert!(a.can_go(&Dir::West));
         assert!(!a.can_go(&Dir::North));
@@ -205,7 +192,7 @@ SJLL7
 |F--J
 LJ.LJ";
         let map: Map = input.parse()?;
-        let a = map.get(&(0, 0)).unwrap();
+        let a = map.get(&Point::new(0, 0)).unwrap();
         assert!(a.can_from(&Dir::North));
         assert!(a.can_from(&Dir::East));
         assert!(!a.can_from(&Dir::South));
@@ -221,13 +208,44 @@ SJLL7
 |F--J
 LJ.LJ";
         let map: Map = input.parse()?;
-        dbg!(map.get(&(4, 3)));
-        dbg!(map.get_surrounds(&(4, 3)));
+        dbg!(map.get(&Point::new(4, 3)));
+        dbg!(map.get_surrounds(&Point::new(4, 3)));
         assert_eq!(
-            map.walk(&(4, 3), &Tile::NorthWest, &(4, 2)),
-            ((3, 3), &Tile::EastWest)
+            map.walk(&Point::new(4, 3), &Tile::NorthWest, &Point::new(4, 2)),
+            (Point::new(3, 3), &Tile::EastWest)
         );
 
         Ok(())
     }
+
+    const TILES: [Tile; 8] = [
+        Tile::NorthSouth,
+        Tile::EastWest,
+        Tile::NorthEast,
+        Tile::NorthWest,
+        Tile::SouthWest,
+        Tile::SouthEast,
+        Tile::Ground,
+        Tile::Starting,
+    ];
+
+    prop_compose! {
+        fn arb_map()(width in 1..20usize, height in 1..20usize)
+            (tiles in vec(select(&TILES[..]), width * height), width in Just(width), height in Just(height))
+            -> Map {
+            Map { tiles: Grid::new(width, height, tiles).unwrap() }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_tile_round_trip(tile in select(&TILES[..])) {
+            prop_assert_eq!(Tile::try_from(char::from(tile)).ok(), Some(tile));
+        }
+
+        #[test]
+        fn test_map_round_trip(map in arb_map()) {
+            prop_assert_eq!(map.to_string().parse::<Map>()?, map);
+        }
+    }
 }
@@ -0,0 +1,145 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+const NORTH: (i64, i64) = (0, -1);
+const SOUTH: (i64, i64) = (0, 1);
+const WEST: (i64, i64) = (-1, 0);
+const EAST: (i64, i64) = (1, 0);
+
+/// Directions a tile opens to, every direction for the start
+fn openings(c: char) -> &'static [(i64, i64)] {
+    match c {
+        '|' => &[NORTH, SOUTH],
+        '-' => &[WEST, EAST],
+        'L' => &[NORTH, EAST],
+        'J' => &[NORTH, WEST],
+        '7' => &[SOUTH, WEST],
+        'F' => &[SOUTH, EAST],
+        'S' => &[NORTH, SOUTH, WEST, EAST],
+        _ => &[],
+    }
+}
+
+struct Sketch {
+    tiles: Vec<Vec<char>>,
+    start: (i64, i64),
+}
+
+impl Sketch {
+    fn new(input: &str) -> Result<Self> {
+        let tiles: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let start = tiles
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| Some((row.iter().position(|&c| c == 'S')? as i64, y as i64)))
+            .ok_or(anyhow!("missing starting point S"))?;
+        Ok(Sketch { tiles, start })
+    }
+
+    fn tile(&self, (x, y): (i64, i64)) -> char {
+        let row = usize::try_from(y).ok().and_then(|y| self.tiles.get(y));
+        let tile = usize::try_from(x).ok().and_then(|x| row?.get(x));
+        tile.copied().unwrap_or('.')
+    }
+
+    /// Directions in which the tile connects to a tile opening back to it
+    fn links(&self, (x, y): (i64, i64)) -> Vec<(i64, i64)> {
+        openings(self.tile((x, y)))
+            .iter()
+            .copied()
+            .filter(|&(dx, dy)| openings(self.tile((x + dx, y + dy))).contains(&(-dx, -dy)))
+            .collect()
+    }
+
+    /// Distance from the start of every tile of the loop, walking both ways at once
+    fn distances(&self) -> HashMap<(i64, i64), usize> {
+        let mut distances = HashMap::from([(self.start, 0)]);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some((x, y)) = queue.pop_front() {
+            for (dx, dy) in self.links((x, y)) {
+                let next = (x + dx, y + dy);
+                if !distances.contains_key(&next) {
+                    distances.insert(next, distances[&(x, y)] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+}
+
+/// Distance of the tile of the loop farthest from the start
+pub fn part1(input: &str) -> Result<Answer> {
+    let distances = Sketch::new(input)?.distances();
+    Ok(distances.into_values().max().unwrap_or(0).into())
+}
+
+/// Tiles enclosed by the loop
+///
+/// The sketch is drawn at twice its scale with a border, leaving gaps between pipes
+/// that do not connect, then flooded from the border: the tiles the water does not
+/// reach and that are not on the loop are enclosed.
+pub fn part2(input: &str) -> Result<Answer> {
+    let sketch = Sketch::new(input)?;
+    let path: Vec<_> = sketch.distances().into_keys().collect();
+    let scale = |(x, y): (i64, i64)| (2 * x + 1, 2 * y + 1);
+    let mut walls = HashSet::new();
+    for &(x, y) in &path {
+        let (sx, sy) = scale((x, y));
+        walls.insert((sx, sy));
+        for (dx, dy) in sketch.links((x, y)) {
+            walls.insert((sx + dx, sy + dy));
+        }
+    }
+
+    let height = sketch.tiles.len() as i64;
+    let width = sketch.tiles.iter().map(Vec::len).max().unwrap_or(0) as i64;
+    let mut water = HashSet::from([(0, 0)]);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((x, y)) = queue.pop_front() {
+        for (dx, dy) in [NORTH, SOUTH, WEST, EAST] {
+            let next = (x + dx, y + dy);
+            let inside = (0..=2 * width).contains(&next.0) && (0..=2 * height).contains(&next.1);
+            if inside && !walls.contains(&next) && water.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut enclosed = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if !walls.contains(&scale((x, y))) && !water.contains(&scale((x, y))) {
+                enclosed += 1;
+            }
+        }
+    }
+    Ok(enclosed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        let input = "7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ";
+        assert_eq!(part1(input)?, 8.into());
+        let input = r"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+        assert_eq!(part2(input)?, 10.into());
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
+use tracing::{debug, instrument};
 
 use crate::map::Map;
+use crate::Day10;
 
-pub fn process(input: &str) -> Result<String> {
-    let map: Map = input.parse()?;
+#[instrument(name = "solve", skip_all)]
+pub fn solve(map: &Map) -> Result<Answer> {
     let (starting_point, starting_tile) = map
         .get_starting()
         .ok_or(anyhow!("missing starting point S"))?;
@@ -19,7 +22,12 @@ pub fn process(input: &str) -> Result<String> {
         steps += 1;
     }
 
-    Ok((steps / 2).to_string())
+    debug!(length = steps, "loop");
+    Ok((steps / 2).into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day10::parse(input)?)
 }
 
 #[cfg(test)]
@@ -33,7 +41,7 @@ mod tests {
 SJLL7
 |F--J
 LJ.LJ";
-        assert_eq!(process(input)?, "8");
+        assert_eq!(process(input)?, 8.into());
         Ok(())
     }
 }
@@ -1,10 +1,13 @@
 use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solver};
 use geo::{Contains, Coord, LineString, Polygon};
+use tracing::{debug, instrument};
 
 use crate::map::Map;
+use crate::Day10;
 
-pub fn process(input: &str) -> Result<String> {
-    let map: Map = input.parse()?;
+#[instrument(name = "solve", skip_all)]
+pub fn solve(map: &Map) -> Result<Answer> {
     let (starting_point, starting_tile) = map
         .get_starting()
         .ok_or(anyhow!("missing starting point S"))?;
@@ -20,15 +23,21 @@ pub fn process(input: &str) -> Result<String> {
         points.push(prev);
     }
 
+    debug!(length = points.len(), "loop");
+    let points: Vec<(i64, i64)> = points.into_iter().map(Into::into).collect();
     let poly = Polygon::new(LineString::from(points), vec![]);
 
     let interiors = map
         .tiles
         .iter()
-        .filter(|(p, _)| poly.contains(&Coord { x: p.0, y: p.1 }))
+        .filter(|(p, _)| poly.contains(&Coord { x: p.x, y: p.y }))
         .count();
 
-    Ok(interiors.to_string())
+    Ok(interiors.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day10::parse(input)?)
 }
 
 #[cfg(test)]
@@ -47,7 +56,7 @@ L---JF-JLJ.||-FJLJJ7
 7-L-JL7||F7|L7F-7F7|
 L.L7LFJ|||||FJL7||LJ
 L7JLJL-JLJLJL--JLJ.L";
-        assert_eq!(process(input)?, "10");
+        assert_eq!(process(input)?, 10.into());
         Ok(())
     }
 }
@@ -1,20 +1,9 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::{anyhow, Error, Result};
+use anyhow::{bail, Error, Result};
+use aoc_grid::{Grid, Point};
 use itertools::Itertools;
 
-type Point = (i64, i64);
-
-trait Distance {
-    fn distance(&self, other: &Self) -> i64;
-}
-
-impl Distance for Point {
-    fn distance(&self, other: &Point) -> i64 {
-        (self.0 - other.0).abs() + (self.1 - other.1).abs()
-    }
-}
-
 #[derive(Debug, PartialEq)]
 pub struct Map {
     galaxies: Vec<Point>,
@@ -26,39 +15,24 @@ impl FromStr for Map {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let galaxies: Vec<_> = s
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter_map(|(x, c)| (c == '#').then_some((x as i64, y as i64)))
-                    .collect::<Vec<_>>()
-            })
-            .collect();
+        let grid: Grid<char> = s.parse()?;
+        if grid.height() == 0 {
+            bail!("missing line");
+        }
 
-        let width = s
-            .lines()
-            .next()
-            .ok_or(anyhow!("missing line"))?
-            .chars()
-            .count();
-        let height = s.lines().count();
-        let empty_rows = (0..height)
-            .filter_map(|row| {
-                galaxies
-                    .iter()
-                    .all(|(_, y)| (*y as usize) != row)
-                    .then_some(row as i64)
-            })
+        let galaxies = grid
+            .iter()
+            .filter_map(|(point, c)| (*c == '#').then_some(point))
             .collect();
-        let empty_cols = (0..width)
-            .filter_map(|col| {
-                galaxies
-                    .iter()
-                    .all(|(x, _)| (*x as usize) != col)
-                    .then_some(col as i64)
-            })
+        let empty_rows = grid
+            .rows()
+            .enumerate()
+            .filter_map(|(y, row)| row.iter().all(|c| *c != '#').then_some(y as i64))
+            .collect();
+        let empty_cols = grid
+            .columns()
+            .enumerate()
+            .filter_map(|(x, mut col)| col.all(|c| *c != '#').then_some(x as i64))
             .collect();
 
         Ok(Map {
@@ -69,12 +43,38 @@ impl FromStr for Map {
     }
 }
 
+/// Write the image, `#` for a galaxy and `.` for empty space
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Every row and column either holds a galaxy or is empty
+        let xs = self
+            .galaxies
+            .iter()
+            .map(|p| p.x)
+            .chain(self.empty_cols.clone());
+        let ys = self
+            .galaxies
+            .iter()
+            .map(|p| p.y)
+            .chain(self.empty_rows.clone());
+        let width = xs.max().map_or(0, |x| x as usize + 1);
+        let height = ys.max().map_or(0, |y| y as usize + 1);
+        let grid = Grid::from_fn(width, height, |point| {
+            match self.galaxies.contains(&point) {
+                true => '#',
+                false => '.',
+            }
+        });
+        write!(f, "{grid}")
+    }
+}
+
 impl Map {
     fn distance(&self, a: &Point, b: &Point, exp: i64) -> i64 {
-        let min_x = a.0.min(b.0);
-        let max_x = a.0.max(b.0);
-        let min_y = a.1.min(b.1);
-        let max_y = a.1.max(b.1);
+        let min_x = a.x.min(b.x);
+        let max_x = a.x.max(b.x);
+        let min_y = a.y.min(b.y);
+        let max_y = a.y.max(b.y);
         let crossed_rows = self
             .empty_rows
             .iter()
@@ -86,7 +86,7 @@ impl Map {
             .filter(|col| (min_x..max_x).contains(col))
             .count() as i64;
 
-        a.distance(b) + (crossed_rows + crossed_cols) * exp
+        a.manhattan(*b) + (crossed_rows + crossed_cols) * exp
     }
 
     pub fn get_distances_sum(&self, exp: i64) -> i64 {
@@ -101,13 +101,15 @@ impl Map {
 
 #[cfg(test)]
 mod tests {
+    use proptest::{collection::vec, prelude::*};
+
     use super::*;
 
     #[test]
     fn test_distance_point() {
-        let a: Point = (6, 1);
-        let b: Point = (10, 5);
-        assert_eq!(a.distance(&b), 8);
+        let a = Point::new(6, 1);
+        let b = Point::new(10, 5);
+        assert_eq!(a.manhattan(b), 8);
     }
 
     const TEST_INPUT: &str = "...#......
@@ -128,15 +130,15 @@ mod tests {
             map,
             Map {
                 galaxies: vec![
-                    (3, 0,),
-                    (7, 1,),
-                    (0, 2,),
-                    (6, 4,),
-                    (1, 5,),
-                    (9, 6,),
-                    (7, 8,),
-                    (0, 9,),
-                    (4, 9,),
+                    Point::new(3, 0),
+                    Point::new(7, 1),
+                    Point::new(0, 2),
+                    Point::new(6, 4),
+                    Point::new(1, 5),
+                    Point::new(9, 6),
+                    Point::new(7, 8),
+                    Point::new(0, 9),
+                    Point::new(4, 9),
                 ],
                 empty_rows: vec![3, 7],
                 empty_cols: vec![2, 5, 8]
@@ -145,6 +147,13 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_display_map() -> Result<()> {
+        let map: Map = TEST_INPUT.parse()?;
+        assert_eq!(map.to_string().trim_end(), TEST_INPUT);
+        Ok(())
+    }
+
     #[test]
     fn test_map_distance() -> Result<()> {
         let map: Map = TEST_INPUT.parse()?;
@@ -164,4 +173,26 @@ mod tests {
 
         Ok(())
     }
+
+    prop_compose! {
+        fn arb_map()(width in 1..20i64, height in 1..20i64)
+            (cells in vec(prop::bool::weighted(0.2), (width * height) as usize), width in Just(width), height in Just(height))
+            -> Map {
+            let galaxies: Vec<_> = (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+                .zip(cells)
+                .filter_map(|(point, galaxy)| galaxy.then_some(point))
+                .collect();
+            let empty_rows = (0..height).filter(|y| galaxies.iter().all(|p| p.y != *y)).collect();
+            let empty_cols = (0..width).filter(|x| galaxies.iter().all(|p| p.x != *x)).collect();
+            Map { galaxies, empty_rows, empty_cols }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_map_round_trip(map in arb_map()) {
+            prop_assert_eq!(map.to_string().parse::<Map>().ok(), Some(map));
+        }
+    }
 }
@@ -0,0 +1,42 @@
+use rand::Rng;
+
+/// Generate a `size` by `size` image where about a tenth of the rows and columns are
+/// empty, galaxies filling 2% of the other cells
+pub fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let empty_rows: Vec<_> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    let empty_cols: Vec<_> = (0..size).map(|_| rng.gen_bool(0.1)).collect();
+    (0..size)
+        .map(|y| {
+            let mut row: String = (0..size)
+                .map(
+                    |x| match !empty_rows[y] && !empty_cols[x] && rng.gen_bool(0.02) {
+                        true => '#',
+                        false => '.',
+                    },
+                )
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input, generate(&mut StdRng::seed_from_u64(1), 50));
+        assert!(input.lines().all(|line| line.len() == 50));
+        assert_eq!(input.lines().count(), 50);
+        part1::process(&input)?;
+        part2::process(&input)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+//! Slow but obviously correct solutions, to check the solvers against
+
+use anyhow::Result;
+use aoc_common::Answer;
+
+/// Grow the image, replacing every empty row and column by `factor` of them
+fn expand(input: &str, factor: usize) -> Vec<Vec<char>> {
+    let mut rows = vec![];
+    for line in input.lines() {
+        let copies = if line.contains('#') { 1 } else { factor };
+        for _ in 0..copies {
+            rows.push(line.chars().collect::<Vec<_>>());
+        }
+    }
+    let width = rows.first().map_or(0, Vec::len);
+    let mut columns: Vec<Vec<char>> = vec![];
+    for x in 0..width {
+        let column: Vec<_> = rows.iter().map(|row| row[x]).collect();
+        let copies = if column.contains(&'#') { 1 } else { factor };
+        for _ in 0..copies {
+            columns.push(column.clone());
+        }
+    }
+    columns
+}
+
+/// Galaxies of the grown image, as `(x, y)`
+fn grown(input: &str, factor: usize) -> Vec<(i64, i64)> {
+    let mut galaxies = vec![];
+    for (x, column) in expand(input, factor).iter().enumerate() {
+        for (y, &c) in column.iter().enumerate() {
+            if c == '#' {
+                galaxies.push((x as i64, y as i64));
+            }
+        }
+    }
+    galaxies
+}
+
+/// Galaxies of the image once grown, as `(x, y)`, each moved by `factor - 1` for every
+/// empty row above it and every empty column left of it
+fn shifted(input: &str, factor: i64) -> Vec<(i64, i64)> {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let empty_row = |y: usize| !rows[y].contains(&'#');
+    let empty_column = |x: usize| rows.iter().all(|row| row[x] != '#');
+    let mut galaxies = vec![];
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '#' {
+                let above = (0..y).filter(|&y| empty_row(y)).count() as i64;
+                let left = (0..x).filter(|&x| empty_column(x)).count() as i64;
+                galaxies.push((
+                    x as i64 + (factor - 1) * left,
+                    y as i64 + (factor - 1) * above,
+                ));
+            }
+        }
+    }
+    galaxies
+}
+
+/// Sum the shortest paths between every pair of galaxies
+fn distances(galaxies: &[(i64, i64)]) -> i64 {
+    let mut total = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            total += (a.0 - b.0).abs() + (a.1 - b.1).abs();
+        }
+    }
+    total
+}
+
+/// Distances with every empty row and column doubled
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(distances(&grown(input, 2)).into())
+}
+
+/// Distances with every empty row and column a million times larger
+///
+/// Such an image does not fit in memory, so the galaxies are moved to where they
+/// would be in it instead.
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(distances(&shifted(input, 1_000_000)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_oracle() -> Result<()> {
+        assert_eq!(part1(TEST_INPUT)?, 374.into());
+        assert_eq!(part2(TEST_INPUT)?, 82000210.into());
+        for (factor, expected) in [(2, 374), (10, 1030), (100, 8410)] {
+            assert_eq!(distances(&grown(TEST_INPUT, factor)), expected);
+            assert_eq!(distances(&shifted(TEST_INPUT, factor as i64)), expected);
+        }
+        Ok(())
+    }
+}
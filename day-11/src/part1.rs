@@ -1,11 +1,18 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
+use tracing::instrument;
 
 use crate::galaxy::Map;
+use crate::Day11;
 
-pub fn process(input: &str) -> Result<String> {
-    let map: Map = input.parse()?;
+#[instrument(name = "solve", skip_all)]
+pub fn solve(map: &Map) -> Result<Answer> {
     let dists = map.get_distances_sum(1);
-    Ok(dists.to_string())
+    Ok(dists.into())
+}
+
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day11::parse(input)?)
 }
 
 #[cfg(test)]
@@ -25,7 +32,7 @@ mod tests {
 
     #[test]
     fn test_process() -> Result<()> {
-        assert_eq!(process(TEST_INPUT)?, "374");
+        assert_eq!(process(TEST_INPUT)?, 374.into());
         Ok(())
     }
 }
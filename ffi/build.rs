@@ -0,0 +1,19 @@
+use std::{env, path::Path};
+
+/// Set to copy the generated header over `include/aoc.h`, which builds never touch otherwise
+const UPDATE_VAR: &str = "AOC_UPDATE_HEADER";
+
+fn main() {
+    let dir = env::var("CARGO_MANIFEST_DIR").expect("cargo to set CARGO_MANIFEST_DIR");
+    let out_dir = env::var("OUT_DIR").expect("cargo to set OUT_DIR");
+    let config = cbindgen::Config::from_file(Path::new(&dir).join("cbindgen.toml"))
+        .expect("read cbindgen.toml");
+    let bindings = cbindgen::generate_with_config(&dir, config).expect("generate the C header");
+    bindings.write_to_file(Path::new(&out_dir).join("aoc.h"));
+    if env::var_os(UPDATE_VAR).is_some() {
+        bindings.write_to_file(Path::new(&dir).join("include/aoc.h"));
+    }
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed={UPDATE_VAR}");
+}
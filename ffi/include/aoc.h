@@ -0,0 +1,66 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/lib.rs, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of a call, the text written to `out` being the answer or the error message
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  /**
+   * `input` or `out` is null
+   */
+  AOC_STATUS_NULL_ARGUMENT = 1,
+  /**
+   * `input` is not valid UTF-8
+   */
+  AOC_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The day has no solver
+   */
+  AOC_STATUS_UNKNOWN_DAY = 3,
+  /**
+   * The part is neither 1 nor 2
+   */
+  AOC_STATUS_UNKNOWN_PART = 4,
+  /**
+   * The solver failed, most likely on an invalid input
+   */
+  AOC_STATUS_FAILED = 5,
+  /**
+   * The solver panicked, which was caught before reaching the caller
+   */
+  AOC_STATUS_PANICKED = 6,
+} AocStatus;
+
+/**
+ * Solve a part of a day
+ *
+ * On return `*out` holds the answer, or the error message when the status is not
+ * `AOC_STATUS_OK`, and must be released with `aoc_free`. `*out` is left untouched
+ * when `out` is null.
+ *
+ * # Safety
+ *
+ * `input` must be null or a NUL terminated string, and `out` must be null or
+ * valid for writes.
+ */
+enum AocStatus aoc_solve(uint8_t day, uint8_t part, const char *input, char **out);
+
+/**
+ * Release a string returned by `aoc_solve`, doing nothing when it is null
+ *
+ * # Safety
+ *
+ * `s` must be null or a string written by `aoc_solve` that was not released yet.
+ */
+void aoc_free(char *s);
+
+#endif  /* AOC_H */
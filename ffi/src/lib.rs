@@ -0,0 +1,145 @@
+//! C interface to the solvers, declared in `include/aoc.h`
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic,
+};
+
+use aoc::{
+    days::{self, Panic, Part},
+    report,
+};
+
+/// Outcome of a call, the text written to `out` being the answer or the error message
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    /// `input` or `out` is null
+    NullArgument = 1,
+    /// `input` is not valid UTF-8
+    InvalidUtf8 = 2,
+    /// The day has no solver
+    UnknownDay = 3,
+    /// The part is neither 1 nor 2
+    UnknownPart = 4,
+    /// The solver failed, most likely on an invalid input
+    Failed = 5,
+    /// The solver panicked, which was caught before reaching the caller
+    Panicked = 6,
+}
+
+fn solve(day: u8, part: u8, input: &CStr) -> Result<String, (AocStatus, String)> {
+    let input = input
+        .to_str()
+        .map_err(|e| (AocStatus::InvalidUtf8, format!("input is not UTF-8: {e}")))?;
+    let day = days::get(day).map_err(|e| (AocStatus::UnknownDay, e.to_string()))?;
+    let part = Part::try_from(part).map_err(|e| (AocStatus::UnknownPart, e.to_string()))?;
+    day.process(part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|e| (AocStatus::Failed, report::render(&e, "<input>")))
+}
+
+/// Solve a part of a day
+///
+/// On return `*out` holds the answer, or the error message when the status is not
+/// `AOC_STATUS_OK`, and must be released with `aoc_free`. `*out` is left untouched
+/// when `out` is null.
+///
+/// # Safety
+///
+/// `input` must be null or a NUL terminated string, and `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const c_char,
+    out: *mut *mut c_char,
+) -> AocStatus {
+    if out.is_null() {
+        return AocStatus::NullArgument;
+    }
+    let (status, text) = if input.is_null() {
+        (AocStatus::NullArgument, "input is null".to_string())
+    } else {
+        let input = CStr::from_ptr(input);
+        match panic::catch_unwind(|| solve(day, part, input)) {
+            Ok(Ok(answer)) => (AocStatus::Ok, answer),
+            Ok(Err(error)) => error,
+            Err(payload) => (AocStatus::Panicked, Panic::new(payload).to_string()),
+        }
+    };
+    let text = CString::new(text.replace('\0', "\\0")).expect("NUL bytes are escaped");
+    *out = text.into_raw();
+    status
+}
+
+/// Release a string returned by `aoc_solve`, doing nothing when it is null
+///
+/// # Safety
+///
+/// `s` must be null or a string written by `aoc_solve` that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    fn call(day: u8, part: u8, input: &str) -> (AocStatus, String) {
+        let input = CString::new(input).unwrap();
+        let mut out = ptr::null_mut();
+        unsafe {
+            let status = aoc_solve(day, part, input.as_ptr(), &mut out);
+            let text = CStr::from_ptr(out).to_string_lossy().into_owned();
+            aoc_free(out);
+            (status, text)
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+        assert_eq!(call(9, 1, input), (AocStatus::Ok, "114".into()));
+        assert_eq!(call(9, 2, input), (AocStatus::Ok, "2".into()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(call(25, 1, "").0, AocStatus::UnknownDay);
+        assert_eq!(call(9, 3, "").0, AocStatus::UnknownPart);
+        let mut out = ptr::null_mut();
+        let status = unsafe { aoc_solve(9, 1, c"\xff".as_ptr(), &mut out) };
+        assert_eq!(status, AocStatus::InvalidUtf8);
+        unsafe { aoc_free(out) };
+        let (status, message) = call(2, 1, "Game 1; 3 blue");
+        assert_eq!(status, AocStatus::Failed);
+        assert!(message.contains("<input>:1:7"));
+        let (status, message) = call(7, 1, "XXXXX 1");
+        assert_eq!(status, AocStatus::Failed);
+        assert!(message.contains("invalid card: unexpected character"));
+
+        let mut out = ptr::null_mut();
+        let status = unsafe { aoc_solve(9, 1, ptr::null(), &mut out) };
+        assert_eq!(status, AocStatus::NullArgument);
+        unsafe { aoc_free(out) };
+        let status = unsafe { aoc_solve(9, 1, c"1 2".as_ptr(), ptr::null_mut()) };
+        assert_eq!(status, AocStatus::NullArgument);
+    }
+
+    #[test]
+    fn test_panic() {
+        // Day 8 expects every node it walks to be in the network
+        let (status, message) = call(8, 1, "L\n\nAAA = (BBB, BBB)\n");
+        assert_eq!(status, AocStatus::Panicked);
+        assert!(message.starts_with("solver panicked: "));
+        assert!(message.contains("to have an entry"));
+    }
+}
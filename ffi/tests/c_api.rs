@@ -0,0 +1,46 @@
+use std::{env, fs, path::Path, process::Command};
+
+use anyhow::{ensure, Result};
+
+/// Compile the C test program against the header and the shared library, then run it
+#[test]
+fn test_c_program() -> Result<()> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Integration tests live in `target/<profile>/deps`, next to the shared library
+    let exe = env::current_exe()?;
+    let lib_dir = exe.parent().unwrap();
+    let program = env::temp_dir().join("aoc-test-ffi-solve");
+
+    let status = Command::new("gcc")
+        .arg(manifest.join("tests/solve.c"))
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-laoc_ffi", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()?;
+    ensure!(status.success(), "gcc failed");
+
+    let output = Command::new(&program).output()?;
+    ensure!(
+        output.status.success(),
+        "the C program failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+/// The header in the source tree is only written on request, so it must be kept in sync
+#[test]
+fn test_header_up_to_date() -> Result<()> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("aoc.h"))?;
+    let checked_in = fs::read_to_string(manifest.join("include/aoc.h"))?;
+    ensure!(
+        generated == checked_in,
+        "include/aoc.h is out of date, run `AOC_UPDATE_HEADER=1 cargo build -p aoc-ffi`"
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static int failures = 0;
+
+static void expect(uint8_t day, uint8_t part, const char *input,
+                   AocStatus status, const char *text) {
+  char *out = NULL;
+  AocStatus actual = aoc_solve(day, part, input, &out);
+  if (actual != status || (text != NULL && strcmp(out, text) != 0)) {
+    fprintf(stderr, "day %d part %d: expected %d `%s`, got %d `%s`\n", day,
+            part, status, text ? text : "", actual, out);
+    failures++;
+  }
+  aoc_free(out);
+}
+
+int main(void) {
+  const char *input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+  expect(9, 1, input, AOC_STATUS_OK, "114");
+  expect(9, 2, input, AOC_STATUS_OK, "2");
+  expect(25, 1, input, AOC_STATUS_UNKNOWN_DAY, "day 25 is not solved yet");
+  expect(9, 3, input, AOC_STATUS_UNKNOWN_PART, NULL);
+  expect(2, 1, "Game 1; 3 blue", AOC_STATUS_FAILED, NULL);
+  expect(7, 1, "XXXXX 1", AOC_STATUS_FAILED, NULL);
+  expect(8, 1, "L\n\nAAA = (BBB, BBB)\n", AOC_STATUS_PANICKED, NULL);
+  expect(9, 1, NULL, AOC_STATUS_NULL_ARGUMENT, "input is null");
+  if (aoc_solve(9, 1, input, NULL) != AOC_STATUS_NULL_ARGUMENT) {
+    failures++;
+  }
+  aoc_free(NULL);
+  return failures;
+}
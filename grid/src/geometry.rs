@@ -0,0 +1,281 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use anyhow::{anyhow, Error, Result};
+
+/// Position or offset on a plane, `y` growing downward like the rows of a grid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Distance when moving along the axes only
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal moves cost the same as straight ones
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Straight line distance
+    pub fn euclidean(self, other: Point) -> f64 {
+        ((self.x - other.x) as f64).hypot((self.y - other.y) as f64)
+    }
+
+    /// Return the next point in the given direction
+    pub fn step(self, dir: Dir) -> Point {
+        self + dir.delta()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Cardinal and ordinal directions, clockwise from the north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir {
+    /// Directions sharing an edge, clockwise from the north
+    pub const CARDINALS: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    /// Every direction, clockwise from the north
+    pub const ALL: [Dir; 8] = [
+        Dir::North,
+        Dir::NorthEast,
+        Dir::East,
+        Dir::SouthEast,
+        Dir::South,
+        Dir::SouthWest,
+        Dir::West,
+        Dir::NorthWest,
+    ];
+
+    /// Offset of a single step in this direction
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::North => Point::new(0, -1),
+            Dir::NorthEast => Point::new(1, -1),
+            Dir::East => Point::new(1, 0),
+            Dir::SouthEast => Point::new(1, 1),
+            Dir::South => Point::new(0, 1),
+            Dir::SouthWest => Point::new(-1, 1),
+            Dir::West => Point::new(-1, 0),
+            Dir::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Dir::CARDINALS.contains(&self)
+    }
+
+    /// Rotate by the given number of eighth of a turn, clockwise
+    fn rotate(self, eighths: usize) -> Dir {
+        Dir::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.rotate(4)
+    }
+
+    /// Quarter turn clockwise
+    pub fn turn_right(self) -> Dir {
+        self.rotate(2)
+    }
+
+    /// Quarter turn counterclockwise
+    pub fn turn_left(self) -> Dir {
+        self.rotate(6)
+    }
+
+    pub fn turn(self, turn: Turn) -> Dir {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+}
+
+impl From<Dir> for Point {
+    fn from(dir: Dir) -> Self {
+        dir.delta()
+    }
+}
+
+impl TryFrom<Point> for Dir {
+    type Error = Error;
+
+    /// Return the direction of a single step offset
+    fn try_from(delta: Point) -> Result<Self, Self::Error> {
+        Dir::ALL
+            .into_iter()
+            .find(|dir| dir.delta() == delta)
+            .ok_or(anyhow!("{delta} is not a single step"))
+    }
+}
+
+/// Quarter turn instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(anyhow!("invalid turn: {value}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(3, -1);
+        assert_eq!(a + b, Point::new(4, 1));
+        assert_eq!(a - b, Point::new(-2, 3));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(6, 1);
+        let b = Point::new(10, 5);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::ORIGIN.euclidean(Point::new(3, 4)), 5.0);
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(Point::ORIGIN.step(Dir::North), Point::new(0, -1));
+        assert_eq!(Point::new(2, 2).step(Dir::SouthWest), Point::new(1, 3));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::North.turn_left(), Dir::West);
+        assert_eq!(Dir::NorthWest.turn_right(), Dir::NorthEast);
+        assert_eq!(Dir::East.turn(Turn::Left), Dir::North);
+        assert_eq!(Dir::South.opposite(), Dir::North);
+        assert_eq!(Dir::SouthEast.opposite(), Dir::NorthWest);
+    }
+
+    #[test]
+    fn test_dir_from_point() {
+        for dir in Dir::ALL {
+            assert_eq!(Dir::try_from(Point::from(dir)).unwrap(), dir);
+        }
+        assert!(Dir::try_from(Point::new(2, 0)).is_err());
+        assert!(Dir::try_from(Point::ORIGIN).is_err());
+    }
+
+    #[test]
+    fn test_is_cardinal() {
+        assert!(Dir::West.is_cardinal());
+        assert!(!Dir::NorthEast.is_cardinal());
+    }
+
+    #[test]
+    fn test_parse_turn() {
+        assert_eq!(Turn::try_from('L').unwrap(), Turn::Left);
+        assert_eq!(Turn::try_from('R').unwrap(), Turn::Right);
+        assert!(Turn::try_from('X').is_err());
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+use aoc_common::parse::ParseError;
+
+use crate::geometry::{Dir, Point};
+
+/// Rectangular grid stored row by row, indexed by `Point { x: column, y: row }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, given row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "expected {} cells for a {width}x{height} grid, got {}",
+                width * height,
+                cells.len()
+            );
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Create a grid by computing the value of each cell
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a grid of characters, mapping each one to a cell
+    pub fn parse_with<E: fmt::Display>(
+        s: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in s.lines() {
+            let start = line.as_ptr() as usize - s.as_ptr() as usize;
+            let mut len = 0;
+            for (i, c) in line.char_indices() {
+                let cell = f(c).map_err(|e| ParseError::new(s, start + i, e.to_string()))?;
+                cells.push(cell);
+                len += 1;
+            }
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let offset = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(ParseError::new(
+                        s,
+                        start + offset,
+                        format!("expected {width} columns, found {len}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    /// Return the cell at the given point, if it is inside the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Iterate over every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    /// Return the position of the first cell matching the predicate
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_of(i))
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        dirs: &'a [Dir],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        dirs.iter().filter_map(move |dir| {
+            let neighbour = point.step(*dir);
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    /// Iterate over the neighbours sharing an edge, clockwise from the north
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &Dir::CARDINALS)
+    }
+
+    /// Iterate over the neighbours sharing an edge or a corner, clockwise from the north
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, &Dir::ALL)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swap the rows and the columns
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotate the grid a quarter turn clockwise
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height as i64;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, height - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width as i64;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(width - 1 - p.y, p.x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside of the grid"))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, T::try_from)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid[Point::new(1, 1)], 'e');
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert!("abc\nde".parse::<Grid<char>>().is_err());
+        let err = "ab\ncde".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected 2 columns, found 3 at line 2, column 3"
+        );
+        assert_eq!(err.offset, 5);
+    }
+
+    #[test]
+    fn test_parse_with_error() {
+        let err = Grid::parse_with("12\n3x", |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!(err.to_string(), "not a digit at line 2, column 2");
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [(Point::new(1, 0), &'b'), (Point::new(0, 1), &'d')]
+        );
+        assert_eq!(
+            grid.neighbours4(Point::new(1, 1))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "bfd"
+        );
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 0))
+                .map(|(_, c)| *c)
+                .collect::<String>(),
+            "cfeda"
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(grid().transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(grid().rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid().rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid().rotate_cw().rotate_ccw(), grid());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid().to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let grid = Grid::from_fn(2, 2, |p| p.x + p.y * 10);
+        assert_eq!(
+            grid.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+            [0, 1, 10, 11]
+        );
+        assert_eq!(grid.position(|v| *v == 10), Some(Point::new(0, 1)));
+    }
+}
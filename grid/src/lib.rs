@@ -0,0 +1,5 @@
+pub mod geometry;
+mod grid;
+
+pub use geometry::{Dir, Point, Turn};
+pub use grid::Grid;
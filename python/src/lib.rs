@@ -0,0 +1,381 @@
+use std::fmt::Display;
+
+use aoc::{
+    days::{self, Part},
+    report,
+};
+use day_05::map::Map;
+use day_07::{part1, part2};
+use day_2::game::{Game, Round};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    pyclass::CompareOp,
+};
+
+fn value_error(e: impl Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Solve a part of a day, returning its answer
+#[pyfunction]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<String> {
+    let part = Part::try_from(part).map_err(value_error)?;
+    let day = days::get(day).map_err(value_error)?;
+    py.allow_threads(|| day.process(part, input))
+        .map(|answer| answer.to_string())
+        .map_err(|e| PyValueError::new_err(report::render(&e, "<input>")))
+}
+
+/// Cubes shown in a round of day 2
+#[pyclass(name = "Round", module = "aoc2023", eq)]
+#[derive(Clone, PartialEq)]
+struct PyRound(Round);
+
+#[pymethods]
+impl PyRound {
+    #[new]
+    #[pyo3(signature = (red = 0, green = 0, blue = 0))]
+    fn new(red: usize, green: usize, blue: usize) -> Self {
+        PyRound(Round { red, green, blue })
+    }
+
+    /// Parse a round such as `3 blue, 4 red`
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        s.parse().map(PyRound).map_err(value_error)
+    }
+
+    #[getter]
+    fn red(&self) -> usize {
+        self.0.red
+    }
+
+    #[getter]
+    fn green(&self) -> usize {
+        self.0.green
+    }
+
+    #[getter]
+    fn blue(&self) -> usize {
+        self.0.blue
+    }
+
+    /// Whether this bag of cubes could have shown the other round
+    fn contains(&self, other: &Self) -> bool {
+        self.0.contains(&other.0)
+    }
+
+    /// Smallest bag of cubes that could have shown both rounds
+    fn __add__(&self, other: &Self) -> Self {
+        PyRound(self.0.clone() + other.0.clone())
+    }
+
+    fn __repr__(&self) -> String {
+        let Round { red, green, blue } = self.0;
+        format!("Round(red={red}, green={green}, blue={blue})")
+    }
+}
+
+/// Game of day 2, the rounds of cubes drawn from the bag
+#[pyclass(name = "Game", module = "aoc2023", eq)]
+#[derive(PartialEq)]
+struct PyGame(Game);
+
+#[pymethods]
+impl PyGame {
+    #[new]
+    fn new(id: usize, rounds: Vec<PyRound>) -> Self {
+        let rounds = rounds.into_iter().map(|round| round.0).collect();
+        PyGame(Game { id, rounds })
+    }
+
+    /// Parse a game such as `Game 1: 3 blue, 4 red; 2 green`
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        s.parse().map(PyGame).map_err(value_error)
+    }
+
+    #[getter]
+    fn id(&self) -> usize {
+        self.0.id
+    }
+
+    #[getter]
+    fn rounds(&self) -> Vec<PyRound> {
+        self.0.rounds.iter().cloned().map(PyRound).collect()
+    }
+
+    /// Fewest cubes of each color that make the game possible
+    fn minimum(&self) -> PyRound {
+        PyRound(self.0.rounds.iter().cloned().sum())
+    }
+
+    fn __repr__(&self) -> String {
+        let rounds: Vec<_> = self.rounds().iter().map(PyRound::__repr__).collect();
+        format!("Game(id={}, rounds=[{}])", self.0.id, rounds.join(", "))
+    }
+}
+
+/// Map of day 5, from source to destination numbers
+#[pyclass(name = "Map", module = "aoc2023", eq)]
+#[derive(PartialEq)]
+struct PyMap(Map);
+
+#[pymethods]
+impl PyMap {
+    /// Build the map between two categories from `(dest, source, length)` ranges, as
+    /// written in the almanac
+    #[new]
+    fn new(source: &str, destination: &str, ranges: Vec<(u64, u64, u64)>) -> PyResult<Self> {
+        let ranges = ranges
+            .into_iter()
+            .map(|(dest, source, length)| {
+                let ends = source.checked_add(length).zip(dest.checked_add(length));
+                ends.map(|(source_end, dest_end)| (source..source_end, dest..dest_end))
+                    .ok_or_else(|| value_error(format!("range of {length} overflows")))
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(PyMap(Map {
+            from: source.to_string(),
+            to: destination.to_string(),
+            ranges,
+        }))
+    }
+
+    /// Parse a `seed-to-soil map:` section followed by its ranges
+    #[staticmethod]
+    fn parse(s: &str) -> PyResult<Self> {
+        s.parse().map(PyMap).map_err(value_error)
+    }
+
+    /// Category of the source numbers, such as `seed`
+    #[getter]
+    fn source(&self) -> &str {
+        &self.0.from
+    }
+
+    /// Category of the destination numbers, such as `soil`
+    #[getter]
+    fn destination(&self) -> &str {
+        &self.0.to
+    }
+
+    /// The `(dest, source, length)` ranges of the map
+    #[getter]
+    fn ranges(&self) -> Vec<(u64, u64, u64)> {
+        self.0
+            .ranges
+            .iter()
+            .map(|(source, dest)| (dest.start, source.start, source.end - source.start))
+            .collect()
+    }
+
+    /// Return the destination of a number, itself when no range contains it
+    fn get(&self, key: u64) -> u64 {
+        self.0.get(key)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.ranges.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Map({:?}, {:?}, {:?})",
+            self.0.from,
+            self.0.to,
+            self.ranges()
+        )
+    }
+}
+
+/// Hand of day 7, ranked by the rules of either part
+enum Rules {
+    Standard(part1::Hand),
+    /// `J` cards are jokers, as in part 2
+    Jokers(part2::Hand),
+}
+
+/// Hand of five Camel Cards of day 7
+#[pyclass(name = "Hand", module = "aoc2023", frozen)]
+struct PyHand {
+    cards: String,
+    rules: Rules,
+}
+
+#[pymethods]
+impl PyHand {
+    #[new]
+    #[pyo3(signature = (cards, jokers = false))]
+    fn new(cards: &str, jokers: bool) -> PyResult<Self> {
+        if cards.chars().count() != 5 || !cards.chars().all(|c| "23456789TJQKA".contains(c)) {
+            return Err(PyValueError::new_err(format!(
+                "invalid hand: {cards}, expected 5 cards out of `23456789TJQKA`"
+            )));
+        }
+        let rules = match jokers {
+            false => Rules::Standard(part1::Hand::new(cards)),
+            true => Rules::Jokers(part2::Hand::new(cards)),
+        };
+        Ok(PyHand {
+            cards: cards.to_string(),
+            rules,
+        })
+    }
+
+    #[getter]
+    fn cards(&self) -> &str {
+        &self.cards
+    }
+
+    #[getter]
+    fn jokers(&self) -> bool {
+        matches!(self.rules, Rules::Jokers(_))
+    }
+
+    /// Name of the type of the hand, such as `FullHouse`
+    #[getter]
+    fn hand_type(&self) -> String {
+        match &self.rules {
+            Rules::Standard(hand) => format!("{:?}", hand.hand_type()),
+            Rules::Jokers(hand) => format!("{:?}", hand.hand_type()),
+        }
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        let ordering = match (&self.rules, &other.rules) {
+            (Rules::Standard(a), Rules::Standard(b)) => a.cmp(b),
+            (Rules::Jokers(a), Rules::Jokers(b)) => a.cmp(b),
+            _ => {
+                return Err(PyTypeError::new_err(
+                    "cannot compare hands ranked with and without jokers",
+                ))
+            }
+        };
+        Ok(op.matches(ordering))
+    }
+
+    fn __repr__(&self) -> String {
+        match self.jokers() {
+            false => format!("Hand('{}')", self.cards),
+            true => format!("Hand('{}', jokers=True)", self.cards),
+        }
+    }
+}
+
+/// Advent of Code 2023 solvers
+#[pymodule]
+fn aoc2023(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_class::<PyRound>()?;
+    m.add_class::<PyGame>()?;
+    m.add_class::<PyMap>()?;
+    m.add_class::<PyHand>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    /// Run Python code with the module imported as `aoc2023`
+    fn run(code: &CStr) -> PyResult<()> {
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "aoc2023")?;
+            aoc2023(&module)?;
+            let globals = PyDict::new(py);
+            globals.set_item("aoc2023", module)?;
+            py.run(code, Some(&globals), None)
+        })
+    }
+
+    #[test]
+    fn test_solve() -> PyResult<()> {
+        run(cr#"
+input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45"
+assert aoc2023.solve(9, 1, input) == "114"
+assert aoc2023.solve(9, 2, input) == "2"
+
+for day, part in [(25, 1), (9, 3)]:
+    try:
+        aoc2023.solve(day, part, input)
+        raise AssertionError("expected a ValueError")
+    except ValueError:
+        pass
+
+try:
+    aoc2023.solve(2, 1, "Game 1; 3 blue")
+    raise AssertionError("expected a ValueError")
+except ValueError as e:
+    assert "invalid game: expected `: `" in str(e)
+    assert "<input>:1:7" in str(e)
+"#)
+    }
+
+    #[test]
+    fn test_game() -> PyResult<()> {
+        run(cr#"
+game = aoc2023.Game.parse("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red")
+assert game.id == 3
+assert game.rounds[1] == aoc2023.Round(red=4, blue=5)
+assert game.minimum() == aoc2023.Round(20, 8, 6)
+assert game == aoc2023.Game(3, [aoc2023.Round(20, 8, 6), aoc2023.Round(4, 0, 5)])
+assert repr(game.rounds[1]) == "Round(red=4, green=0, blue=5)"
+
+bag = aoc2023.Round(red=12, green=13, blue=14)
+assert not all(bag.contains(round) for round in game.rounds)
+assert aoc2023.Round.parse("1 red") + aoc2023.Round(blue=2) == aoc2023.Round(1, 0, 2)
+"#)
+    }
+
+    #[test]
+    fn test_map() -> PyResult<()> {
+        run(cr#"
+map = aoc2023.Map.parse("seed-to-soil map:\n50 98 2\n52 50 48")
+assert len(map) == 2
+assert map.ranges == [(50, 98, 2), (52, 50, 48)]
+assert [map.get(seed) for seed in [79, 14, 55, 13, 99]] == [81, 14, 57, 13, 51]
+assert (map.source, map.destination) == ("seed", "soil")
+assert map == aoc2023.Map("seed", "soil", [(50, 98, 2), (52, 50, 48)])
+assert map != aoc2023.Map("seed", "water", [(50, 98, 2), (52, 50, 48)])
+assert repr(map) == 'Map("seed", "soil", [(50, 98, 2), (52, 50, 48)])'
+
+try:
+    aoc2023.Map("seed", "soil", [(2**64 - 1, 0, 2)])
+    raise AssertionError("expected a ValueError")
+except ValueError as e:
+    assert "overflows" in str(e)
+"#)
+    }
+
+    #[test]
+    fn test_hand() -> PyResult<()> {
+        run(cr#"
+hands = [aoc2023.Hand(cards) for cards in ["32T3K", "T55J5", "KK677", "KTJJT", "QQQJA"]]
+assert [hand.cards for hand in sorted(hands)] == ["32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"]
+assert hands[1].hand_type == "ThreeOfKind"
+
+jokers = aoc2023.Hand("KTJJT", jokers=True)
+assert jokers.hand_type == "FourOfKind"
+assert jokers > aoc2023.Hand("QQQJA", jokers=True)
+assert repr(jokers) == "Hand('KTJJT', jokers=True)"
+
+for invalid in ["32T3", "32T3X"]:
+    try:
+        aoc2023.Hand(invalid)
+        raise AssertionError("expected a ValueError")
+    except ValueError:
+        pass
+try:
+    jokers < hands[0]
+    raise AssertionError("expected a TypeError")
+except TypeError:
+    pass
+"#)
+    }
+}
@@ -1,2 +1,24 @@
+use anyhow::Result;
+use aoc_common::{Answer, Solver};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day{{day}};
+
+impl Solver for Day{{day}} {
+    const DAY: u8 = {{day_number}};
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -1,9 +1,16 @@
 use anyhow::Result;
+use aoc_common::{Answer, Solver};
 
-pub fn process(input: &str) -> Result<String> {
+use crate::Day{{day}};
+
+pub fn solve(_lines: &[&str]) -> Result<Answer> {
     todo!()
 }
 
+pub fn process(input: &str) -> Result<Answer> {
+    solve(&Day{{day}}::parse(input)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;